@@ -1,11 +1,16 @@
+use crate::runtime::abort;
+use crate::sui_std::object::object::{self, KeyObject, UID};
+use crate::sui_std::tx_context::tx_context::TxContext;
 use crate::sui_std::balance::balance;
 use balance::Balance;
 use crate::sui_std::coin::coin;
 use coin::Coin;
+use crate::sui_std::transfer::transfer;
+use crate::sui_std::sui::sui::SUI;
 
 pub struct NFT {
-    id: u8,
-    price: Balance,
+    id: UID,
+    price: Balance<SUI>,
 }
 
 pub struct Loan {
@@ -13,37 +18,22 @@ pub struct Loan {
 }
 
 pub struct LoanPool {
-    id: u8,
-    amount: Balance,
+    id: UID,
+    amount: Balance<SUI>,
 }
 
-const ERepayAmountInvalid: u64 = 1;
+pub const ERepayAmountInvalid: u64 = 1;
 
-const ELoanAmountExceedPool: u64 = 0;
+pub const ELoanAmountExceedPool: u64 = 0;
 
-use std::sync::LazyLock;
-
-pub struct IdGetter {
-    current_id: std::sync::Mutex<u8>,
+impl KeyObject for LoanPool {
+    fn uid(&self) -> &UID { &self.id }
 }
 
-impl IdGetter {
-    pub fn new() -> Self {
-        IdGetter {
-            current_id: std::sync::Mutex::new(0),
-        }
-    }
-
-    pub fn get_new_id(&self) -> u8 {
-        let mut id = self.current_id.lock().unwrap();
-        *id += 1;
-        *id
-    }
+impl KeyObject for NFT {
+    fn uid(&self) -> &UID { &self.id }
 }
 
-// Use LazyLock to initialize ID_GETTER
-pub static ID_GETTER: LazyLock<IdGetter> = LazyLock::new(|| IdGetter::new());
-
 // Copyright (c) Sui Foundation, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
@@ -64,28 +54,28 @@ impl flashloan__flashloan {
     /// This is a hot potato struct, it enforces the users
     /// to repay the loan in the end of the transaction or within the same PTB.
     /// A dummy NFT to represent the flashloan fnctionality
-    pub fn init() {
+    pub fn init(ctx: &mut TxContext) {
         let pool = LoanPool { 
-            id: ID_GETTER.get_new_id(), 
+            id: object::new(ctx), 
             amount: balance::zero() 
         };
-        pool;
+        transfer::share_object(pool);
     }
     // === Public-Mutative Functions ===
 
     /// Deposit money into loan pool
-    pub fn deposit_pool(pool: &mut LoanPool, deposit: Coin) {
+    pub fn deposit_pool(pool: &mut LoanPool, deposit: Coin<SUI>) {
         balance::join(&mut pool.amount, coin::into_balance(deposit));
     }
 
     /// Function allows users to borrow from the loan pool.
     /// It returns the borrowed [`Coin`] and the [`Loan`] position 
     /// enforcing users to fulfill before the PTB ends.
-    pub fn borrow(pool: &mut LoanPool, amount: u64, ) -> (Coin, Loan) {
-        assert!(amount <= balance::value(&pool.amount), "{}", ELoanAmountExceedPool);
+    pub fn borrow(pool: &mut LoanPool, amount: u64, ctx: &mut TxContext) -> (Coin<SUI>, Loan) {
+        abort::check(amount <= balance::value(&pool.amount), "flashloan", "borrow", ELoanAmountExceedPool);
 
         (
-            coin::from_balance(balance::split(&mut pool.amount, amount)),
+            coin::from_balance(balance::split(&mut pool.amount, amount), ctx),
             Loan {
                 amount
             }
@@ -94,24 +84,25 @@ impl flashloan__flashloan {
 
     /// Repay the loan
     /// Users must execute this fnction to ensure the loan is repaid before the transaction ends.
-    pub fn repay(pool: &mut LoanPool, loan: Loan, payment: Coin) {
+    pub fn repay(pool: &mut LoanPool, loan: Loan, payment: Coin<SUI>) {
         let Loan { amount } = loan;
-        assert!(coin::value(&payment) == amount, "{}", ERepayAmountInvalid);
+        abort::check(coin::value(&payment) == amount, "flashloan", "repay", ERepayAmountInvalid);
 
         balance::join(&mut pool.amount, coin::into_balance(payment));
     }
 
     /// Mint NFT
-    pub fn mint_nft(payment: Coin, ) -> NFT {
+    pub fn mint_nft(payment: Coin<SUI>, ctx: &mut TxContext) -> NFT {
         NFT {
-            id: ID_GETTER.get_new_id(),
+            id: object::new(ctx),
             price: coin::into_balance(payment),
         }
     }
 
     /// Sell NFT
-    pub fn sell_nft(nft: NFT, ) -> Coin {
+    pub fn sell_nft(nft: NFT, ctx: &mut TxContext) -> Coin<SUI> {
         let NFT {id, price} = nft;
-        coin::from_balance(price)
+        object::delete(id);
+        coin::from_balance(price, ctx)
     }
 }   
\ No newline at end of file
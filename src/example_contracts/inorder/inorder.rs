@@ -1,49 +1,30 @@
-
+use crate::sui_std::object::object::{self, KeyObject, UID};
+use crate::sui_std::transfer::transfer;
+use crate::sui_std::tx_context::tx_context::TxContext;
+use crate::runtime::arith;
+use crate::runtime::abort;
 pub struct CallRegistry {
-    id: u8,
+    id: UID,
     num_calls: u64,
     a_called: bool,
     b_called: bool,
     c_called: bool,
 }
 
-const EInvariantBroken: u64 = 3;
-
-const ECCallRequired: u64 = 2;
-
-const EBCallRequired: u64 = 1;
-
-const EACallRequired: u64 = 0;
-
-use std::sync::LazyLock;
-
-pub struct IdGetter {
-    current_id: std::sync::Mutex<u8>,
-}
+pub const EInvariantBroken: u64 = 3;
 
-impl IdGetter {
-    pub fn new() -> Self {
-        IdGetter {
-            current_id: std::sync::Mutex::new(0),
-        }
-    }
+pub const ECCallRequired: u64 = 2;
 
-    pub fn get_new_id(&self) -> u8 {
-        let mut id = self.current_id.lock().unwrap();
-        *id += 1;
-        *id
-    }
-}
+pub const EBCallRequired: u64 = 1;
 
-// Use LazyLock to initialize ID_GETTER
-pub static ID_GETTER: LazyLock<IdGetter> = LazyLock::new(|| IdGetter::new());
+pub const EACallRequired: u64 = 0;
 
 pub struct inorder__inorder {}
 impl inorder__inorder {
 
-    pub fn init() -> CallRegistry {
+    pub fn init(ctx: &mut TxContext) {
         let call_registry = CallRegistry {
-            id: ID_GETTER.get_new_id(),
+            id: object::new(ctx),
             num_calls: 0,
             a_called: false,
             b_called: false,
@@ -51,43 +32,47 @@ impl inorder__inorder {
         };
 
         let veriman = call_registry.a_called;
-        assert!(!veriman || call_registry.a_called, "{}", EInvariantBroken);
+        abort::check(!veriman || call_registry.a_called, "inorder", "init", EInvariantBroken);
 
-        call_registry
+        transfer::share_object(call_registry);
     }
 
     pub fn a(call_registry: &mut CallRegistry) {
         let veriman = call_registry.a_called;
         call_registry.a_called = true;
-        call_registry.num_calls+=1;
+        call_registry.num_calls = arith::add(call_registry.num_calls, 1);
 
-        assert!(!veriman || call_registry.a_called, "{}", EInvariantBroken);
+        abort::check(!veriman || call_registry.a_called, "inorder", "a", EInvariantBroken);
     }
 
     pub fn b(call_registry: &mut CallRegistry) {
         let veriman = call_registry.a_called;
-        assert!(call_registry.a_called, "{}", EACallRequired);
+        abort::check(call_registry.a_called, "inorder", "b", EACallRequired);
         
         if call_registry.b_called {
             call_registry.num_calls = 0;
-            assert!(!veriman || call_registry.a_called, "{}", EInvariantBroken);
+            abort::check(!veriman || call_registry.a_called, "inorder", "b", EInvariantBroken);
             return;
         }
 
         call_registry.b_called = true;
-        call_registry.num_calls+=1;
+        call_registry.num_calls = arith::add(call_registry.num_calls, 1);
 
-        assert!(!veriman || call_registry.a_called, "{}", EInvariantBroken);
+        abort::check(!veriman || call_registry.a_called, "inorder", "b", EInvariantBroken);
     }
 
     pub fn c(call_registry: &mut CallRegistry) -> u64 {
-        assert!(call_registry.a_called, "{}", EACallRequired);
-        assert!(call_registry.b_called, "{}", EBCallRequired);
+        abort::check(call_registry.a_called, "inorder", "c", EACallRequired);
+        abort::check(call_registry.b_called, "inorder", "c", EBCallRequired);
         
         call_registry.c_called = true;
-        call_registry.num_calls+=1;
+        call_registry.num_calls = arith::add(call_registry.num_calls, 1);
 
         3
     }
 
-}   
\ No newline at end of file
+}   
+
+impl KeyObject for CallRegistry {
+    fn uid(&self) -> &UID { &self.id }
+}
\ No newline at end of file
@@ -1,41 +1,21 @@
+use crate::sui_std::object::object::{self, KeyObject, UID};
+use crate::sui_std::tx_context::tx_context::TxContext;
+use crate::sui_std::option::option;
+use crate::runtime::abort;
+pub const ENotEquipped: u64 = 1;
 
-const ENotEquipped: u64 = 1;
-
-const EAlreadyEquipped: u64 = 0;
+pub const EAlreadyEquipped: u64 = 0;
 
 pub struct Warrior {
-    id: u8,
+    id: UID,
     sword: Option<Sword>,
 }
 
 pub struct Sword {
-    id: u8,
+    id: UID,
     strength: u8,
 }
 
-use std::sync::LazyLock;
-
-pub struct IdGetter {
-    current_id: std::sync::Mutex<u8>,
-}
-
-impl IdGetter {
-    pub fn new() -> Self {
-        IdGetter {
-            current_id: std::sync::Mutex::new(0),
-        }
-    }
-
-    pub fn get_new_id(&self) -> u8 {
-        let mut id = self.current_id.lock().unwrap();
-        *id += 1;
-        *id
-    }
-}
-
-// Use LazyLock to initialize ID_GETTER
-pub static ID_GETTER: LazyLock<IdGetter> = LazyLock::new(|| IdGetter::new());
-
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
@@ -46,22 +26,30 @@ impl simple_warrior__example {
 
     /// Warrior does not have a sword equipped.
 
-    pub fn new_sword(strength: u8, ) -> Sword {
-        Sword { id: ID_GETTER.get_new_id(), strength }
+    pub fn new_sword(strength: u8, ctx: &mut TxContext) -> Sword {
+        Sword { id: object::new(ctx), strength }
     }
 
-    pub fn new_warrior() -> Warrior {
-        Warrior { id: ID_GETTER.get_new_id(), sword: None }
+    pub fn new_warrior(ctx: &mut TxContext) -> Warrior {
+        Warrior { id: object::new(ctx), sword: None }
     }
 
     pub fn equip(warrior: &mut Warrior, sword: Sword) {
-        assert!(warrior.sword.is_none(), "{}", EAlreadyEquipped);
-        assert!(warrior.sword.replace(sword).is_none());
+        abort::check(warrior.sword.is_none(), "example", "equip", EAlreadyEquipped);
+        abort::check(warrior.sword.is_none(), "option", "fill", option::EOPTION_IS_SET); warrior.sword = Some(sword);
     }
 
     pub fn unequip(warrior: &mut Warrior) -> Sword {
-        assert!(warrior.sword.is_some(), "{}", ENotEquipped);
-        warrior.sword.take().unwrap()
+        abort::check(warrior.sword.is_some(), "example", "unequip", ENotEquipped);
+        { abort::check(warrior.sword.is_some(), "option", "extract", option::EOPTION_NOT_SET); warrior.sword.take().unwrap() }
     }
 
+}
+
+impl KeyObject for Sword {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+impl KeyObject for Warrior {
+    fn uid(&self) -> &UID { &self.id }
 }
\ No newline at end of file
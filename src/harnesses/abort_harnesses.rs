@@ -0,0 +1,49 @@
+use crate::runtime::abort;
+use crate::sui_std::balance::balance;
+use crate::sui_std::coin::coin;
+use crate::sui_std::sui::sui::SUI;
+use crate::sui_std::tx_context::tx_context;
+
+//////////////////// MOVE ABORTS
+
+#[kani::proof]
+fn split_aborts_exactly_when_balance_is_short() {
+    let have: u64 = kani::any();
+    let want: u64 = kani::any();
+    kani::assume(have < u64::MAX && want > have);
+    let mut supply = balance::create_supply(());
+    let mut b = supply.increase_supply(have);
+
+    abort::expect("balance", "split", balance::ENotEnough);
+    balance::split(&mut b, want);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+#[kani::should_panic]
+fn abort_with_other_code_fails_expectation() {
+    let mut ctx = tx_context::dummy();
+    let mut c = coin::zero::<SUI>(&mut ctx);
+    abort::expect("coin", "divide_into_n", coin::ENotEnough);
+    coin::divide_into_n(&mut c, 0, &mut ctx);
+}
+
+#[kani::proof]
+#[kani::should_panic]
+fn missing_abort_fails_expectation() {
+    let mut b = balance::zero::<SUI>();
+    abort::expect("balance", "split", balance::ENotEnough);
+    balance::split(&mut b, 0);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn forbid_lets_other_aborts_reject_the_transaction() {
+    let mut ctx = tx_context::dummy();
+    let mut c = coin::zero::<SUI>(&mut ctx);
+    let n: u64 = kani::any();
+    abort::forbid("coin", coin::ENotEnough);
+    kani::assume(n == 0);
+    coin::divide_into_n(&mut c, n, &mut ctx);
+    unreachable!("dividing into zero coins aborts");
+}
@@ -0,0 +1,56 @@
+use crate::runtime::{self, abort};
+use crate::runtime::u256::{u256, U256};
+use crate::sui_std::address::address::{self, Address, SYMBOLIC_ADDRESSES};
+use crate::sui_std::coin::coin::{self, Coin};
+use crate::sui_std::sui::sui::SUI;
+use crate::sui_std::transfer::transfer;
+use crate::sui_std::tx_context::tx_context;
+
+//////////////////// ADDRESS
+
+#[kani::proof]
+fn literals_are_equal_however_they_are_written() {
+    assert!(address::from_literal("@0x1") == Address::from_u128(1));
+    assert!(address::from_literal("0x000000000000000000000000000000000000000000000000000000000000000A") == Address::from_u128(0xA));
+    assert!(address::from_literal("@0x1") != address::from_literal("@0x10"));
+    assert!(address::parse("@0xnothex").is_none());
+    assert!(address::to_u256(address::from_literal("@0x100000000000000000000000000000000")) == U256::from_limbs(1, 0));
+}
+
+#[kani::proof]
+fn conversions_round_trip() {
+    let n: u256 = kani::any();
+    let a = address::from_u256(n);
+    assert!(address::to_u256(a) == n);
+    assert!(address::from_bytes(address::to_bytes(a)) == a);
+}
+
+#[kani::proof]
+fn string_round_trips_through_ascii_bytes() {
+    let a: Address = kani::any();
+    let hex = address::to_string(a);
+    assert!(hex.len() as u64 == 2 * address::length());
+    assert!(address::from_ascii_bytes(&hex.into_bytes()) == a);
+}
+
+#[kani::proof]
+fn short_bytes_abort() {
+    let len: usize = kani::any();
+    kani::assume(len < 32);
+    abort::expect("address", "from_bytes", address::EAddressParseError);
+    address::from_bytes(vec![0u8; len]);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn coins_reach_symbolic_recipients() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    let recipient: Address = kani::any();
+    assert!(SYMBOLIC_ADDRESSES.contains(&recipient));
+    transfer::pub_transfer(coin::zero::<SUI>(&mut ctx), recipient);
+
+    let other: Address = kani::any();
+    let found = transfer::most_recent_id_for_address::<Coin<SUI>>(&other);
+    assert!(found.is_some() == (other == recipient));
+}
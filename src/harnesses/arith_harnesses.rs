@@ -0,0 +1,110 @@
+use crate::example_contracts::simple_ds_chief::simple_ds_chief::{self, SimpleDSChief__SimpleDSChief};
+use crate::runtime::abort;
+use crate::runtime::arith::{self, ARITHMETIC_ERROR, VM};
+use crate::runtime::u256::{u256, U256};
+use crate::sui_std::balance::balance;
+
+//////////////////// MOVE ARITHMETIC
+
+#[kani::proof]
+fn join_aborts_on_overflow() {
+    let a: u64 = kani::any();
+    let b: u64 = kani::any();
+    kani::assume(a < u64::MAX && b < u64::MAX && a.checked_add(b).is_none());
+    let mut supply_a = balance::create_supply(());
+    let mut supply_b = balance::create_supply(());
+    let mut left = supply_a.increase_supply(a);
+    let right = supply_b.increase_supply(b);
+
+    abort::expect(VM, "add", ARITHMETIC_ERROR);
+    balance::join(&mut left, right);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn chief_add_check_is_unreachable() {
+    let x: u64 = kani::any();
+    let y: u64 = kani::any();
+    // Move aborts on the overflowing addition before add's own check can fail.
+    abort::forbid("SimpleDSChief", simple_ds_chief::EAddShouldBeGreater);
+    let z = SimpleDSChief__SimpleDSChief::add(x, y);
+    assert!(z - y == x);
+}
+
+#[kani::proof]
+fn division_by_zero_aborts() {
+    let a: u128 = kani::any();
+    abort::expect(VM, "div", ARITHMETIC_ERROR);
+    arith::div(a, 0);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn shift_by_width_aborts_but_loses_bits_below() {
+    let a: u8 = kani::any();
+    let amount: u8 = kani::any();
+    kani::assume(amount < 8);
+    assert!(arith::shl(a, amount) == a.wrapping_shl(amount as u32));
+    assert!(arith::shr(a, amount) == a >> amount);
+
+    abort::expect(VM, "shl", ARITHMETIC_ERROR);
+    arith::shl(a, 8);
+    abort::assert_aborted();
+}
+
+//////////////////// U256
+
+#[kani::proof]
+fn u256_matches_u128_where_both_fit() {
+    let a: u128 = kani::any();
+    let b: u128 = kani::any();
+    let (x, y) = (U256::from_u128(a), U256::from_u128(b));
+    assert!((x < y) == (a < b));
+    if let Some(sum) = a.checked_add(b) {
+        assert!(arith::add(x, y) == U256::from_u128(sum));
+    }
+    if let Some(difference) = a.checked_sub(b) {
+        assert!(arith::sub(x, y) == U256::from_u128(difference));
+    }
+    if b != 0 {
+        assert!(arith::div(x, y) == U256::from_u128(a / b));
+        assert!(arith::rem(x, y) == U256::from_u128(a % b));
+    }
+}
+
+#[kani::proof]
+fn u256_carries_between_halves() {
+    let a: u128 = kani::any();
+    let wide = arith::shl(U256::from_u128(a), 128);
+    assert!(wide == U256::from_limbs(a, 0));
+    assert!(arith::shr(wide, 128) == U256::from_u128(a));
+    assert!(arith::add(U256::from_u128(u128::MAX), U256::ONE) == U256::from_limbs(1, 0));
+    assert!(arith::sub(U256::from_limbs(1, 0), U256::ONE) == U256::from_u128(u128::MAX));
+    assert!(arith::mul(U256::from_u128(1 << 127), U256::from_u128(4)) == U256::from_limbs(2, 0));
+    assert!(arith::div(U256::from_limbs(1, 0), U256::from_u128(2)) == U256::from_u128(1 << 127));
+}
+
+#[kani::proof]
+fn u256_overflow_aborts() {
+    let a: u256 = kani::any();
+    kani::assume(a > U256::ONE);
+    abort::expect(VM, "mul", ARITHMETIC_ERROR);
+    arith::mul(arith::add(U256::MAX.shr(1), U256::ONE), a);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn cast_aborts_when_value_does_not_fit() {
+    let a: u256 = kani::any();
+    match a.to_u128() {
+        Some(small) if small <= u64::MAX as u128 => {
+            assert!(arith::cast::<u256, u64>(a) as u128 == small);
+            assert!(arith::cast::<u64, u256>(small as u64) == a);
+        }
+        _ => {
+            abort::expect(VM, "cast", ARITHMETIC_ERROR);
+            arith::cast::<u256, u64>(a);
+            abort::assert_aborted();
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::runtime::{self, abort};
+use crate::sui_std::bag::bag::{self, Bag};
+use crate::sui_std::coin::coin::{self, Coin};
+use crate::sui_std::dynamic_field::dynamic_field;
+use crate::sui_std::object::object;
+use crate::sui_std::object_bag::object_bag;
+use crate::sui_std::sui::sui::SUI;
+use crate::sui_std::transfer::transfer::{self, Owner};
+use crate::sui_std::tx_context::tx_context;
+
+//////////////////// BAG
+
+#[kani::proof]
+fn bag_holds_values_of_different_types() {
+    let mut ctx = tx_context::dummy();
+    let mut inventory = bag::new(&mut ctx);
+    let gold: u64 = kani::any();
+
+    bag::add(&mut inventory, 0u8, gold);
+    bag::add(&mut inventory, b"sword".to_vec(), true);
+    bag::add(&mut inventory, 1u8, coin::zero::<SUI>(&mut ctx));
+    assert!(bag::length(&inventory) == 3);
+    assert!(bag::contains_with_type::<u8, u64>(&inventory, 0));
+    assert!(!bag::contains_with_type::<u8, bool>(&inventory, 0));
+    assert!(*bag::borrow::<Vec<u8>, bool>(&inventory, b"sword".to_vec()));
+
+    let coin: Coin<SUI> = bag::remove(&mut inventory, 1u8);
+    coin::destroy_zero(coin);
+    assert!(bag::remove::<u8, u64>(&mut inventory, 0) == gold);
+    assert!(!bag::contains(&inventory, 0u8));
+    assert!(bag::length(&inventory) == 1);
+}
+
+#[kani::proof]
+fn borrowing_with_the_wrong_type_aborts() {
+    let mut ctx = tx_context::dummy();
+    let mut inventory = bag::new(&mut ctx);
+    bag::add(&mut inventory, 0u8, 1u64);
+
+    abort::expect("dynamic_field", "borrow", dynamic_field::EFieldTypeMismatch);
+    bag::borrow::<u8, u32>(&inventory, 0);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn destroying_a_bag_with_values_aborts() {
+    let mut ctx = tx_context::dummy();
+    let mut inventory: Bag = bag::new(&mut ctx);
+    bag::add(&mut inventory, 0u8, 1u64);
+
+    abort::expect("bag", "destroy_empty", bag::EBagNotEmpty);
+    bag::destroy_empty(inventory);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn object_bag_keeps_objects_addressable() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    let mut inventory = object_bag::new(&mut ctx);
+    let coin = coin::zero::<SUI>(&mut ctx);
+    let coin_id = object::id(&coin);
+
+    object_bag::add(&mut inventory, 7u64, coin);
+    assert!(object_bag::value_id(&inventory, 7u64) == Some(coin_id));
+    assert!(transfer::owner(&coin_id) == Some(Owner::ObjectOwner(object::id(&inventory))));
+    assert!(object_bag::contains_with_type::<u64, Coin<SUI>>(&inventory, 7));
+
+    let coin: Coin<SUI> = object_bag::remove(&mut inventory, 7u64);
+    assert!(object::id(&coin) == coin_id);
+    assert!(object_bag::value_id(&inventory, 7u64).is_none());
+    object_bag::destroy_empty(inventory);
+    coin::destroy_zero(coin);
+}
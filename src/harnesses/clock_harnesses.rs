@@ -0,0 +1,83 @@
+use crate::runtime::{self, abort};
+use crate::sui_std::clock::clock::{self, Clock};
+use crate::sui_std::transfer::transfer;
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+
+/// Tokens locked until `cliff_ms`.
+const ECliffNotReached: u64 = 0;
+
+fn claim(clock: &Clock, cliff_ms: u64, locked: &mut u64) -> u64 {
+    abort::check(clock.timestamp_ms() >= cliff_ms, "vesting", "claim", ECliffNotReached);
+    std::mem::take(locked)
+}
+
+//////////////////// CLOCK
+
+#[kani::proof]
+fn time_never_goes_backwards() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    clock::share_for_testing(clock::create_for_testing(&mut ctx));
+
+    let mut clock = transfer::take_shared::<Clock>();
+    clock::advance(&mut clock);
+    let first = clock::timestamp_ms(&clock);
+    transfer::return_object(clock);
+
+    let mut clock = transfer::take_shared::<Clock>();
+    clock::advance(&mut clock);
+    assert!(clock::timestamp_ms(&clock) >= first);
+    transfer::return_object(clock);
+}
+
+#[kani::proof]
+fn tokens_cannot_be_claimed_before_the_cliff() {
+    let mut ctx = tx_context::dummy();
+    let mut clock = clock::create_for_testing(&mut ctx);
+    let cliff_ms: u64 = kani::any();
+    let amount: u64 = kani::any();
+    let mut locked = amount;
+    clock::advance(&mut clock);
+
+    abort::allow();
+    let claimed = claim(&clock, cliff_ms, &mut locked);
+    assert!(clock::timestamp_ms(&clock) >= cliff_ms);
+    assert!(claimed == amount && locked == 0);
+}
+
+#[kani::proof]
+fn claiming_before_the_cliff_aborts() {
+    let mut ctx = tx_context::dummy();
+    let mut clock = clock::create_for_testing(&mut ctx);
+    clock::advance(&mut clock);
+    let cliff_ms: u64 = kani::any();
+    kani::assume(clock::timestamp_ms(&clock) < cliff_ms);
+    let mut locked: u64 = kani::any();
+
+    abort::expect("vesting", "claim", ECliffNotReached);
+    claim(&clock, cliff_ms, &mut locked);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn set_for_testing_rejects_going_back() {
+    let mut ctx = tx_context::dummy();
+    let mut clock = clock::create_for_testing(&mut ctx);
+    clock::increment_for_testing(&mut clock, 10);
+    let earlier: u64 = kani::any();
+    kani::assume(earlier < 10);
+
+    abort::expect("clock", "set_for_testing", 0);
+    clock::set_for_testing(&mut clock, earlier);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn only_the_system_sets_the_time() {
+    let mut clock = clock::create_for_testing(&mut tx_context::dummy());
+    let ctx: TxContext = kani::any();
+
+    abort::expect("clock", "consensus_commit_prologue", clock::ENotSystemAddress);
+    clock::consensus_commit_prologue(&mut clock, kani::any(), &ctx);
+    abort::assert_aborted();
+}
@@ -0,0 +1,142 @@
+use crate::runtime::{self, abort, deny};
+use crate::sui_std::address::address::Address;
+use crate::sui_std::coin::coin::{self, Coin, DenyCapV2, TreasuryCap};
+use crate::sui_std::deny_list::deny_list::{self, DenyList};
+use crate::sui_std::transfer::transfer;
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+
+struct STABLECOIN {}
+
+fn setup(allow_global_pause: bool, ctx: &mut TxContext) -> (TreasuryCap<STABLECOIN>, DenyCapV2<STABLECOIN>, DenyList) {
+    let (cap, deny_cap, _metadata) =
+        coin::create_regulated_currency_v2(STABLECOIN {}, 6, vec![], vec![], vec![], None, allow_global_pause, ctx);
+    (cap, deny_cap, deny_list::new_for_testing(ctx))
+}
+
+//////////////////// DENY LIST
+
+#[kani::proof]
+fn denied_address_still_receives_until_the_epoch_ends() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    let (mut cap, mut deny_cap, mut list) = setup(false, &mut ctx);
+    let denied: Address = kani::any();
+
+    coin::deny_list_v2_add(&mut list, &mut deny_cap, denied, &mut ctx);
+    assert!(coin::deny_list_v2_contains_next_epoch::<STABLECOIN>(&list, denied));
+    assert!(!coin::deny_list_v2_contains_current_epoch::<STABLECOIN>(&list, denied, &ctx));
+    coin::mint_and_transfer(&mut cap, 1, denied, &mut ctx);
+    assert!(transfer::most_recent_id_for_address::<Coin<STABLECOIN>>(&denied).is_some());
+
+    tx_context::increment_epoch_number(&mut ctx);
+    assert!(coin::deny_list_v2_contains_current_epoch::<STABLECOIN>(&list, denied, &ctx));
+    kani::cover!(true, "a denied address is sent a coin in the next epoch");
+    abort::expect("transfer", "transfer_impl", deny::EAddressDeniedForCoin);
+    coin::mint_and_transfer(&mut cap, 1, denied, &mut ctx);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn denied_address_cannot_spend_immediately() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    let (mut cap, mut deny_cap, mut list) = setup(false, &mut ctx);
+    let denied: Address = kani::any();
+    coin::mint_and_transfer(&mut cap, 1, denied, &mut ctx);
+
+    coin::deny_list_v2_add(&mut list, &mut deny_cap, denied, &mut ctx);
+    kani::cover!(true, "a denied address spends a coin it received before");
+    abort::expect("transfer", "take_by_id", deny::EAddressDeniedForCoin);
+    let _ = transfer::take_from_address::<Coin<STABLECOIN>>(denied);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn only_denied_addresses_are_blocked() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    let (mut cap, mut deny_cap, mut list) = setup(false, &mut ctx);
+    let denied: Address = kani::any();
+    let recipient: Address = kani::any();
+
+    coin::deny_list_v2_add(&mut list, &mut deny_cap, denied, &mut ctx);
+    tx_context::increment_epoch_number(&mut ctx);
+    kani::cover!(recipient == denied, "the recipient is denied");
+    kani::cover!(recipient != denied, "the recipient is someone else");
+    if recipient == denied {
+        abort::expect("transfer", "transfer_impl", deny::EAddressDeniedForCoin);
+        coin::mint_and_transfer(&mut cap, 1, recipient, &mut ctx);
+        abort::assert_aborted();
+    } else {
+        coin::mint_and_transfer(&mut cap, 1, recipient, &mut ctx);
+        let c = transfer::take_from_address::<Coin<STABLECOIN>>(recipient);
+        assert!(c.value() == 1);
+    }
+}
+
+#[kani::proof]
+fn removal_takes_effect_for_receipts_at_the_next_epoch() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    let (_cap, mut deny_cap, mut list) = setup(false, &mut ctx);
+    let addr: Address = kani::any();
+
+    coin::deny_list_v2_add(&mut list, &mut deny_cap, addr, &mut ctx);
+    tx_context::increment_epoch_number(&mut ctx);
+    coin::deny_list_v2_remove(&mut list, &mut deny_cap, addr, &mut ctx);
+    assert!(coin::deny_list_v2_contains_current_epoch::<STABLECOIN>(&list, addr, &ctx));
+    assert!(!coin::deny_list_v2_contains_next_epoch::<STABLECOIN>(&list, addr));
+
+    tx_context::increment_epoch_number(&mut ctx);
+    assert!(!coin::deny_list_v2_contains_current_epoch::<STABLECOIN>(&list, addr, &ctx));
+    kani::cover!(true, "the removal takes effect");
+}
+
+#[kani::proof]
+fn global_pause_blocks_everyone_but_lists_no_one() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    let (mut cap, mut deny_cap, mut list) = setup(true, &mut ctx);
+    let recipient: Address = kani::any();
+
+    coin::deny_list_v2_enable_global_pause(&mut list, &mut deny_cap, &mut ctx);
+    assert!(coin::deny_list_v2_is_global_pause_enabled_next_epoch::<STABLECOIN>(&list));
+    tx_context::increment_epoch_number(&mut ctx);
+    assert!(coin::deny_list_v2_is_global_pause_enabled_current_epoch::<STABLECOIN>(&list, &ctx));
+    assert!(!coin::deny_list_v2_contains_current_epoch::<STABLECOIN>(&list, recipient, &ctx));
+    kani::cover!(true, "anyone is sent a coin during the pause");
+
+    abort::expect("transfer", "transfer_impl", deny::EAddressDeniedForCoin);
+    coin::mint_and_transfer(&mut cap, 1, recipient, &mut ctx);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn global_pause_needs_the_cap_to_allow_it() {
+    let mut ctx = tx_context::dummy();
+    let (_cap, mut deny_cap, mut list) = setup(false, &mut ctx);
+
+    abort::expect("coin", "deny_list_v2_enable_global_pause", coin::EGlobalPauseNotAllowed);
+    coin::deny_list_v2_enable_global_pause(&mut list, &mut deny_cap, &mut ctx);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn reserved_addresses_cannot_be_denied() {
+    let mut ctx = tx_context::dummy();
+    let (_cap, mut deny_cap, mut list) = setup(false, &mut ctx);
+
+    abort::expect("deny_list", "v2_add", deny_list::EInvalidAddress);
+    coin::deny_list_v2_add(&mut list, &mut deny_cap, Address::from_u128(0x2), &mut ctx);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn only_the_system_creates_the_deny_list() {
+    let sender: Address = kani::any();
+    let mut ctx = tx_context::new(sender, vec![0u8; 32], 0, 0, 0);
+
+    abort::expect("deny_list", "create", deny_list::ENotSystemAddress);
+    deny_list::create(&mut ctx);
+    abort::assert_aborted();
+}
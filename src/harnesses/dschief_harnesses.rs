@@ -1,6 +1,9 @@
-use crate::example_contracts::simple_ds_chief::simple_ds_chief::{Address, DSChief, SimpleDSChief__SimpleDSChief};
+use crate::example_contracts::simple_ds_chief::simple_ds_chief::{DSChief, SimpleDSChief__SimpleDSChief};
+use crate::sui_std::address::address::{self, Address, SYMBOLIC_ADDRESSES};
 use crate::sui_std::table::table;
 use crate::sui_std::table::table::{Table, Key};
+use crate::sui_std::object::object;
+use crate::sui_std::tx_context::tx_context;
 use kani::Arbitrary;
 use std::collections::{HashMap, HashSet};
 
@@ -8,17 +11,6 @@ fn bounded_any() -> u64 {
     kani::any_where(|x: &u64| *x == 0 || *x == 1 || *x == 2)
 }
 
-impl Arbitrary for Address {
-    // Custom method to generate arbitrary `Address`
-    fn any() -> Self {
-        // Generate arbitrary `u64` value for `id`
-        let id: u64 = bounded_any();
-        Address {
-            id: id,
-        }
-    }
-}
-
 pub fn arbitrary_hashmap<K, V>() -> HashMap<K, V>
 where
     K: Arbitrary + Eq + std::hash::Hash + Clone,
@@ -40,14 +32,13 @@ where
 impl<K: Key + Arbitrary + Clone, V: Arbitrary + Clone> Arbitrary for Table<K, V> {
     // Custom method to generate arbitrary `Address`
     fn any() -> Self {
-        // Generate arbitrary `u64` value for `id`
         let map = arbitrary_hashmap();
         let size = map.len();
-        let id = bounded_any() as u8;
+        let mut ctx = tx_context::dummy();
         Table {
-            id: id,
+            id: object::new(&mut ctx),
             map: map,
-            size: size as u8, // Cast is safe as size is less than 10
+            size: size as u64,
         }
     }
 }
@@ -62,28 +53,26 @@ impl Arbitrary for DSChief {
             common_keys.insert(Address::any());
         }
 
-        let mut votes = table::new();
-        let mut approvals = table::new();
-        let mut deposits = table::new();
+        let mut ctx = tx_context::dummy();
+        let mut votes = table::new(&mut ctx);
+        let mut approvals = table::new(&mut ctx);
+        let mut deposits = table::new(&mut ctx);
 
         for key in &common_keys {
-            table::add(&mut votes, key.clone(), bounded_any());
+            table::add(&mut votes, key.clone(), address::to_u256(kani::any()));
             table::add(&mut approvals, key.clone(), bounded_any());
             table::add(&mut deposits, key.clone(), bounded_any());
         }
 
-        let id: u8 = bounded_any() as u8;
-
-        // Generate arbitrary `u64` value for `id`
         let dschief = DSChief {
-            id: id,
+            id: object::new(&mut ctx),
             slates: kani::any(),
             votes: votes,
             approvals: approvals,
             deposits: deposits,
         };
         for key in dschief.slates.map.keys() {
-            kani::assume(*key == 0 || *key == 1 || *key == 2);
+            kani::assume(SYMBOLIC_ADDRESSES.iter().any(|a| address::to_u256(*a) == *key));
         };
         
 
@@ -0,0 +1,67 @@
+use crate::runtime::abort;
+use crate::sui_std::dynamic_field::dynamic_field;
+use crate::sui_std::object::object;
+use crate::sui_std::tx_context::tx_context;
+
+//////////////////// DYNAMIC FIELDS
+
+#[kani::proof]
+fn field_round_trips() {
+    let mut ctx = tx_context::dummy();
+    let mut uid = object::new(&mut ctx);
+    let name: u64 = kani::any();
+    let value: u64 = kani::any();
+    kani::assume(value < u64::MAX);
+
+    dynamic_field::add(&mut uid, name, value);
+    assert!(dynamic_field::exists_with_type::<u64, u64>(&uid, name));
+    assert!(*dynamic_field::borrow::<u64, u64>(&uid, name) == value);
+    *dynamic_field::borrow_mut::<u64, u64>(&mut uid, name) += 1;
+    assert!(dynamic_field::remove::<u64, u64>(&mut uid, name) == value + 1);
+    assert!(!dynamic_field::exists_(&uid, name));
+}
+
+#[kani::proof]
+fn names_of_different_types_are_different_fields() {
+    let mut ctx = tx_context::dummy();
+    let mut uid = object::new(&mut ctx);
+
+    dynamic_field::add(&mut uid, 1u8, true);
+    dynamic_field::add(&mut uid, 1u64, 7u64);
+    assert!(*dynamic_field::borrow::<u8, bool>(&uid, 1u8));
+    assert!(*dynamic_field::borrow::<u64, u64>(&uid, 1u64) == 7);
+    assert!(!dynamic_field::exists_(&uid, 1u16));
+}
+
+#[kani::proof]
+fn adding_a_name_twice_aborts() {
+    let mut ctx = tx_context::dummy();
+    let mut uid = object::new(&mut ctx);
+    dynamic_field::add(&mut uid, 0u8, 1u64);
+
+    abort::expect("dynamic_field", "add", dynamic_field::EFieldAlreadyExists);
+    dynamic_field::add(&mut uid, 0u8, false);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn borrowing_a_missing_field_aborts() {
+    let mut ctx = tx_context::dummy();
+    let uid = object::new(&mut ctx);
+
+    abort::expect("dynamic_field", "borrow", dynamic_field::EFieldDoesNotExist);
+    dynamic_field::borrow::<u8, u64>(&uid, 0);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn removing_with_the_wrong_value_type_aborts() {
+    let mut ctx = tx_context::dummy();
+    let mut uid = object::new(&mut ctx);
+    dynamic_field::add(&mut uid, 0u8, 1u64);
+    assert!(!dynamic_field::exists_with_type::<u8, bool>(&uid, 0));
+
+    abort::expect("dynamic_field", "remove", dynamic_field::EFieldTypeMismatch);
+    dynamic_field::remove::<u8, bool>(&mut uid, 0);
+    abort::assert_aborted();
+}
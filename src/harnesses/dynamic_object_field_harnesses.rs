@@ -0,0 +1,71 @@
+use crate::runtime::{self, abort};
+use crate::sui_std::balance::balance;
+use crate::sui_std::coin::coin::{self, Coin};
+use crate::sui_std::dynamic_field::dynamic_field;
+use crate::sui_std::dynamic_object_field::dynamic_object_field;
+use crate::sui_std::object::object::{self, KeyObject, UID};
+use crate::sui_std::sui::sui::SUI;
+use crate::sui_std::transfer::transfer::{self, Owner};
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+
+struct Wallet {
+    id: UID,
+}
+
+impl KeyObject for Wallet {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+fn new_wallet(ctx: &mut TxContext) -> Wallet {
+    Wallet { id: object::new(ctx) }
+}
+
+//////////////////// DYNAMIC OBJECT FIELDS
+
+#[kani::proof]
+fn removed_child_is_returned_to_the_caller() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    let mut wallet = new_wallet(&mut ctx);
+    let amount: u64 = kani::any();
+    kani::assume(amount < u64::MAX);
+    let mut supply = balance::create_supply(SUI {});
+    let coin = coin::from_balance(supply.increase_supply(amount), &mut ctx);
+    let coin_id = object::id(&coin);
+
+    dynamic_object_field::add(&mut wallet.id, 0u8, coin);
+    assert!(dynamic_object_field::id(&wallet.id, 0u8) == Some(coin_id));
+    assert!(dynamic_object_field::parent(&coin_id) == Some(object::id(&wallet)));
+    assert!(transfer::owner(&coin_id) == Some(Owner::ObjectOwner(object::id(&wallet))));
+    assert!(coin::value(dynamic_object_field::borrow::<u8, Coin<SUI>>(&wallet.id, 0)) == amount);
+
+    let removed: Coin<SUI> = dynamic_object_field::remove(&mut wallet.id, 0u8);
+    assert!(object::id(&removed) == coin_id);
+    assert!(coin::value(&removed) == amount);
+    assert!(!dynamic_object_field::exists_(&wallet.id, 0u8));
+    assert!(dynamic_object_field::parent(&coin_id).is_none());
+    assert!(transfer::owner(&coin_id) == Some(Owner::Wrapped));
+}
+
+#[kani::proof]
+fn object_fields_do_not_collide_with_plain_fields() {
+    let mut ctx = tx_context::dummy();
+    let mut wallet = new_wallet(&mut ctx);
+
+    dynamic_field::add(&mut wallet.id, 0u8, 5u64);
+    dynamic_object_field::add(&mut wallet.id, 0u8, coin::zero::<SUI>(&mut ctx));
+    assert!(dynamic_object_field::exists_with_type::<u8, Coin<SUI>>(&wallet.id, 0));
+    assert!(!dynamic_object_field::exists_with_type::<u8, Wallet>(&wallet.id, 0));
+    assert!(*dynamic_field::borrow::<u8, u64>(&wallet.id, 0) == 5);
+}
+
+#[kani::proof]
+fn removing_child_as_another_type_aborts() {
+    let mut ctx = tx_context::dummy();
+    let mut wallet = new_wallet(&mut ctx);
+    dynamic_object_field::add(&mut wallet.id, 0u8, coin::zero::<SUI>(&mut ctx));
+
+    abort::expect("dynamic_field", "remove", dynamic_field::EFieldTypeMismatch);
+    let _: Wallet = dynamic_object_field::remove(&mut wallet.id, 0u8);
+    abort::assert_aborted();
+}
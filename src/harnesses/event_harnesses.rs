@@ -0,0 +1,49 @@
+use crate::runtime::abort;
+use crate::sui_std::coin::coin::{self, Coin, TreasuryCap};
+use crate::sui_std::event::event;
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+
+struct WITNESS {}
+
+#[derive(Clone, PartialEq)]
+struct MintEvent {
+    amount: u64,
+}
+
+/// Rejects empty mints, after the event is already out.
+const EZeroMint: u64 = 0;
+
+fn mint(cap: &mut TreasuryCap<WITNESS>, amount: u64, ctx: &mut TxContext) -> Coin<WITNESS> {
+    event::emit(MintEvent { amount });
+    abort::check(amount > 0, "minter", "mint", EZeroMint);
+    coin::mint(cap, amount, ctx)
+}
+
+//////////////////// EVENTS
+
+#[kani::proof]
+fn every_successful_mint_emits_one_mint_event() {
+    let mut ctx = tx_context::dummy();
+    let (mut cap, _metadata) = coin::create_currency(WITNESS {}, 6, vec![], vec![], vec![], None, &mut ctx);
+    let mut ctx = tx_context::dummy();
+    let amount: u64 = kani::any();
+
+    abort::allow();
+    let minted = mint(&mut cap, amount, &mut ctx);
+    assert!(event::num_events() == 1);
+    assert!(event::events_by_type::<MintEvent>() == vec![MintEvent { amount: coin::value(&minted) }]);
+    assert!(amount > 0);
+}
+
+#[kani::proof]
+fn each_transaction_starts_with_an_empty_log() {
+    let ctx = tx_context::dummy();
+    event::emit(MintEvent { amount: kani::any() });
+    event::emit(0u8);
+    assert!(event::num_events() == 2);
+    assert!(event::events_by_type::<u8>() == vec![0]);
+    drop(ctx);
+
+    let _ctx = tx_context::dummy();
+    assert!(event::num_events() == 0);
+}
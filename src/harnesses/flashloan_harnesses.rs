@@ -1,15 +1,20 @@
-
-use crate::example_contracts::flashloan::flashloan::flashloan__flashloan;
+use crate::example_contracts::flashloan::flashloan::{self, flashloan__flashloan, LoanPool};
+use crate::runtime::abort;
+use crate::sui_std::transfer::transfer;
 use crate::sui_std::coin::coin;
+use crate::sui_std::sui::sui::SUI;
 use crate::sui_std::balance::balance;
+use crate::sui_std::tx_context::tx_context;
 
 #[kani::proof]
-#[kani::should_panic]
 fn try_flashloan() {
-    let mut pool = flashloan__flashloan::init();
-    let mut b = balance::zero();
-    let mut c = coin::from_balance(b);
+    let mut ctx = tx_context::dummy();
+    flashloan__flashloan::init(&mut ctx);
+    let mut pool = transfer::take_shared::<LoanPool>();
+    let mut b = balance::zero::<SUI>();
+    let mut c = coin::from_balance(b, &mut ctx);
 
-    flashloan__flashloan::borrow(&mut pool, 100);
-    
-}
\ No newline at end of file
+    abort::expect("flashloan", "borrow", flashloan::ELoanAmountExceedPool);
+    flashloan__flashloan::borrow(&mut pool, 100, &mut ctx);
+    abort::assert_aborted();
+}
@@ -1,9 +1,15 @@
-use crate::example_contracts::inorder::inorder::inorder__inorder;
+use crate::example_contracts::inorder::inorder::{self, inorder__inorder, CallRegistry};
+use crate::runtime::abort;
+use crate::sui_std::transfer::transfer;
+use crate::sui_std::tx_context::tx_context;
 
 #[kani::proof]
 #[kani::unwind(5)]
 pub fn try_generic_inorder() {
-    let mut call_registry = inorder__inorder::init();
+    let mut ctx = tx_context::dummy();
+    abort::forbid("inorder", inorder::EInvariantBroken);
+    inorder__inorder::init(&mut ctx);
+    let mut call_registry = transfer::take_shared::<CallRegistry>();
     while true {
         let x: u8 = kani::any();
         kani::assume(x < 3);
@@ -0,0 +1,66 @@
+use crate::runtime::abort;
+use crate::sui_std::address::address::Address;
+use crate::sui_std::dynamic_field::dynamic_field;
+use crate::sui_std::linked_table::linked_table::{self, LinkedTable};
+use crate::sui_std::tx_context::tx_context;
+
+//////////////////// LINKED TABLE
+
+#[kani::proof]
+fn withdrawal_requests_are_served_in_insertion_order() {
+    let mut ctx = tx_context::dummy();
+    let mut requests: LinkedTable<u64, u64> = linked_table::new(&mut ctx);
+    let ids: [u64; 3] = [kani::any(), kani::any(), kani::any()];
+    kani::assume(ids[0] != ids[1] && ids[0] != ids[2] && ids[1] != ids[2]);
+
+    for (i, id) in ids.iter().enumerate() {
+        linked_table::push_back(&mut requests, *id, i as u64);
+    }
+    assert!(linked_table::keys(&requests, 3) == ids.to_vec());
+    for (i, id) in ids.iter().enumerate() {
+        assert!(linked_table::pop_front(&mut requests) == (*id, i as u64));
+    }
+    assert!(linked_table::is_empty(&requests));
+    linked_table::destroy_empty(requests);
+}
+
+#[kani::proof]
+fn removing_splices_the_entry_out() {
+    let mut ctx = tx_context::dummy();
+    let mut book = linked_table::new(&mut ctx);
+    linked_table::push_back(&mut book, 2u8, true);
+    linked_table::push_front(&mut book, 1u8, false);
+    linked_table::push_back(&mut book, 3u8, true);
+    let k: u8 = kani::any_where(|k: &u8| *k >= 1 && *k <= 3);
+
+    linked_table::remove(&mut book, k);
+    let rest: Vec<u8> = [1, 2, 3].into_iter().filter(|other| *other != k).collect();
+    assert!(linked_table::keys(&book, 3) == rest);
+    assert!(*linked_table::front(&book) == Some(rest[0]));
+    assert!(*linked_table::back(&book) == Some(rest[1]));
+    assert!(*linked_table::next(&book, rest[0]) == Some(rest[1]));
+    assert!(*linked_table::prev(&book, rest[1]) == Some(rest[0]));
+    assert!(linked_table::pop_back(&mut book).0 == rest[1]);
+}
+
+#[kani::proof]
+fn popping_an_empty_table_aborts() {
+    let mut ctx = tx_context::dummy();
+    let mut queue: LinkedTable<Address, u64> = linked_table::new(&mut ctx);
+
+    abort::expect("linked_table", "pop_front", linked_table::ETableIsEmpty);
+    linked_table::pop_front(&mut queue);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn pushing_a_key_twice_aborts() {
+    let mut ctx = tx_context::dummy();
+    let mut queue = linked_table::new(&mut ctx);
+    let sender: Address = kani::any();
+    linked_table::push_back(&mut queue, sender, 1u64);
+
+    abort::expect("dynamic_field", "add", dynamic_field::EFieldAlreadyExists);
+    linked_table::push_front(&mut queue, sender, 2u64);
+    abort::assert_aborted();
+}
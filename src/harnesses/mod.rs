@@ -1,5 +1,26 @@
-//pub(crate) mod flashloan_harnesses;
+pub(crate) mod flashloan_harnesses;
 pub(crate) mod simple_warrior_harnesses;
 pub(crate) mod inorder_harnesses;
 pub(crate) mod dschief_harnesses;
-pub(crate) mod example_kani;
\ No newline at end of file
+pub(crate) mod example_kani;
+pub(crate) mod object_harnesses;
+pub(crate) mod tx_context_harnesses;
+pub(crate) mod transfer_harnesses;
+pub(crate) mod abort_harnesses;
+pub(crate) mod arith_harnesses;
+pub(crate) mod address_harnesses;
+pub(crate) mod dynamic_field_harnesses;
+pub(crate) mod dynamic_object_field_harnesses;
+pub(crate) mod vec_map_harnesses;
+pub(crate) mod vec_set_harnesses;
+pub(crate) mod bag_harnesses;
+pub(crate) mod object_table_harnesses;
+pub(crate) mod linked_table_harnesses;
+pub(crate) mod table_vec_harnesses;
+pub(crate) mod priority_queue_harnesses;
+pub(crate) mod clock_harnesses;
+pub(crate) mod event_harnesses;
+pub(crate) mod random_harnesses;
+pub(crate) mod pay_harnesses;
+pub(crate) mod token_harnesses;
+pub(crate) mod deny_list_harnesses;
\ No newline at end of file
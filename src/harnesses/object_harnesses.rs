@@ -0,0 +1,74 @@
+use crate::runtime;
+use crate::runtime::ids;
+use crate::sui_std::address::address::{self, Address};
+use crate::sui_std::object::object;
+use crate::sui_std::tx_context::tx_context;
+
+//////////////////// OBJECT IDS
+
+#[kani::proof]
+fn deleted_uid_is_never_reused() {
+    let mut ctx = tx_context::dummy();
+    let first = object::new(&mut ctx);
+    let first_id = object::uid_to_inner(&first);
+    object::delete(first);
+
+    let second = object::new(&mut ctx);
+    assert!(!object::is_live(&first_id));
+    assert!(object::uid_to_inner(&second) != first_id);
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn live_objects_never_share_an_id() {
+    let mut ctx = tx_context::dummy();
+    let n: u8 = kani::any();
+    kani::assume(n < 4);
+
+    let mut uids = Vec::new();
+    for _ in 0..n {
+        uids.push(object::new(&mut ctx));
+    }
+    for i in 0..uids.len() {
+        for j in 0..i {
+            assert!(object::uid_as_inner(&uids[i]) != object::uid_as_inner(&uids[j]));
+        }
+    }
+}
+
+#[kani::proof]
+fn id_round_trips_through_address() {
+    let mut ctx = tx_context::dummy();
+    let uid = object::new(&mut ctx);
+    let id = object::uid_to_inner(&uid);
+    assert!(object::id_from_address(object::id_to_address(&id)) == id);
+    assert!(object::id_from_bytes(object::id_to_bytes(&id)) == id);
+}
+
+#[kani::proof]
+fn live_count_follows_new_and_delete() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    let a = object::new(&mut ctx);
+    let b = object::new(&mut ctx);
+    assert!(ids::live_count() == 2);
+
+    let deleted = object::uid_to_inner(&a);
+    object::delete(a);
+    assert!(ids::live_count() == 1);
+    assert!(!object::is_live(&deleted));
+    assert!(object::is_live(object::uid_as_inner(&b)));
+}
+
+#[kani::proof]
+fn any_address_is_an_id() {
+    let hi: u128 = kani::any();
+    let a = Address::from_limbs(hi, kani::any());
+    let id = object::id_from_address(a);
+    assert!(object::id_to_address(&id) == a);
+    assert!(object::id_from_bytes(address::to_bytes(a)) == id);
+    // Past the IDs `runtime::ids` hands out, no object has it.
+    if hi != 0 {
+        assert!(!object::is_live(&id));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::runtime::{self, abort};
+use crate::sui_std::address::address::Address;
+use crate::sui_std::dynamic_field::dynamic_field;
+use crate::sui_std::object::object::{self, KeyObject, UID};
+use crate::sui_std::object_table::object_table::{self, ObjectTable};
+use crate::sui_std::transfer::transfer::{self, Owner};
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+
+struct Position {
+    id: UID,
+    shares: u64,
+}
+
+impl KeyObject for Position {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+fn new_position(shares: u64, ctx: &mut TxContext) -> Position {
+    Position { id: object::new(ctx), shares }
+}
+
+//////////////////// OBJECT TABLE
+
+#[kani::proof]
+fn stored_objects_stay_live_and_addressable() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    let mut registry: ObjectTable<Address, Position> = object_table::new(&mut ctx);
+    let holder: Address = kani::any();
+    let shares: u64 = kani::any();
+    let position = new_position(shares, &mut ctx);
+    let position_id = object::id(&position);
+
+    object_table::add(&mut registry, holder, position);
+    assert!(object::is_live(&position_id));
+    assert!(object_table::value_id(&registry, holder) == Some(position_id));
+    assert!(transfer::owner(&position_id) == Some(Owner::ObjectOwner(object::id(&registry))));
+    assert!(object_table::borrow(&registry, holder).shares == shares);
+    object_table::borrow_mut(&mut registry, holder).shares = 0;
+
+    let position = object_table::remove(&mut registry, holder);
+    assert!(object::id(&position) == position_id && position.shares == 0);
+    assert!(!object_table::contains(&registry, holder));
+    object_table::destroy_empty(registry);
+    transfer::transfer(position, holder);
+    assert!(transfer::owner(&position_id) == Some(Owner::AddressOwner(holder)));
+}
+
+#[kani::proof]
+fn adding_a_key_twice_aborts() {
+    let mut ctx = tx_context::dummy();
+    let mut registry = object_table::new(&mut ctx);
+    let holder: Address = kani::any();
+    object_table::add(&mut registry, holder, new_position(1, &mut ctx));
+
+    abort::expect("dynamic_field", "add", dynamic_field::EFieldAlreadyExists);
+    object_table::add(&mut registry, holder, new_position(2, &mut ctx));
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn destroying_a_table_with_objects_aborts() {
+    let mut ctx = tx_context::dummy();
+    let mut registry = object_table::new(&mut ctx);
+    object_table::add(&mut registry, 0u64, new_position(1, &mut ctx));
+
+    abort::expect("object_table", "destroy_empty", object_table::ETableNotEmpty);
+    object_table::destroy_empty(registry);
+    abort::assert_aborted();
+}
@@ -0,0 +1,72 @@
+use crate::runtime::{self, abort};
+use crate::sui_std::address::address::Address;
+use crate::sui_std::balance::balance;
+use crate::sui_std::coin::coin::{self, Coin};
+use crate::sui_std::pay::pay;
+use crate::sui_std::sui::sui::SUI;
+use crate::sui_std::transfer::transfer;
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+
+fn coin_worth(value: u64, ctx: &mut TxContext) -> Coin<SUI> {
+    let mut supply = balance::create_supply(SUI {});
+    coin::from_balance(supply.increase_supply(value), ctx)
+}
+
+//////////////////// PAY
+
+#[kani::proof]
+fn splitting_and_joining_conserves_value() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    let total: u64 = kani::any();
+    let a: u64 = kani::any();
+    let b: u64 = kani::any();
+    kani::assume(total < u64::MAX && a <= total && b <= total - a);
+    let mut c = coin_worth(total, &mut ctx);
+
+    c.split_vec(vec![a, b], &mut ctx);
+    assert!(c.value() == total - a - b);
+
+    let sender = tx_context::sender(&ctx);
+    let second = transfer::take_from_address::<Coin<SUI>>(sender);
+    let first = transfer::take_from_address::<Coin<SUI>>(sender);
+    assert!(first.value() == a && second.value() == b);
+    c.join_vec(vec![first, second]);
+    assert!(c.value() == total);
+}
+
+#[kani::proof]
+fn split_and_transfer_pays_the_recipient() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    let recipient: Address = kani::any();
+    let amount: u64 = kani::any();
+    kani::assume(amount <= 100);
+    let mut c = coin_worth(100, &mut ctx);
+
+    c.split_and_transfer(amount, recipient, &mut ctx);
+    let paid = transfer::take_from_address::<Coin<SUI>>(recipient);
+    assert!(paid.value() == amount);
+    assert!(c.value() == 100 - amount);
+}
+
+#[kani::proof]
+fn divide_and_keep_leaves_the_remainder() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    let mut c = coin_worth(10, &mut ctx);
+
+    c.divide_and_keep(3, &mut ctx);
+    assert!(c.value() == 4);
+    let kept = transfer::take_from_address::<Coin<SUI>>(tx_context::sender(&ctx));
+    assert!(kept.value() == 3);
+}
+
+#[kani::proof]
+fn joining_no_coins_aborts() {
+    let recipient: Address = kani::any();
+
+    abort::expect("pay", "join_vec_and_transfer", pay::ENoCoins);
+    pay::join_vec_and_transfer::<SUI>(vec![], recipient);
+    abort::assert_aborted();
+}
@@ -0,0 +1,51 @@
+use crate::runtime::abort;
+use crate::sui_std::priority_queue::priority_queue::{self, PriorityQueue};
+
+/// A queue built from four arbitrary priorities, each value remembering its priority.
+fn arbitrary_queue() -> PriorityQueue<u64> {
+    let p: Vec<u64> = vec![kani::any(), kani::any(), kani::any(), kani::any()];
+    priority_queue::new(priority_queue::create_entries(p.clone(), p))
+}
+
+//////////////////// PRIORITY QUEUE
+
+#[kani::proof]
+fn pop_max_returns_a_maximal_priority() {
+    let mut pq = arbitrary_queue();
+    let (priority, value) = priority_queue::pop_max(&mut pq);
+    assert!(priority == value);
+    assert!(priority_queue::priorities(&pq).iter().all(|p| *p <= priority));
+}
+
+#[kani::proof]
+fn pop_max_stays_maximal_after_insert() {
+    let mut pq = arbitrary_queue();
+    priority_queue::pop_max(&mut pq);
+    let priority: u64 = kani::any();
+    priority_queue::insert(&mut pq, priority, priority);
+
+    let (max, _) = priority_queue::pop_max(&mut pq);
+    assert!(max >= priority);
+    assert!(priority_queue::priorities(&pq).iter().all(|p| *p <= max));
+}
+
+#[kani::proof]
+fn pops_come_out_in_non_increasing_order() {
+    let mut pq = arbitrary_queue();
+    let mut last = u64::MAX;
+    for _ in 0..4 {
+        let (priority, _) = priority_queue::pop_max(&mut pq);
+        assert!(priority <= last);
+        last = priority;
+    }
+    assert!(priority_queue::priorities(&pq).is_empty());
+}
+
+#[kani::proof]
+fn popping_an_empty_queue_aborts() {
+    let mut pq: PriorityQueue<u64> = priority_queue::new(vec![]);
+
+    abort::expect("priority_queue", "pop_max", priority_queue::EPopFromEmptyHeap);
+    priority_queue::pop_max(&mut pq);
+    abort::assert_aborted();
+}
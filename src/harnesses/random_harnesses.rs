@@ -0,0 +1,75 @@
+use crate::runtime::{self, abort};
+use crate::sui_std::address::address::Address;
+use crate::sui_std::random::random::{self, Random};
+use crate::sui_std::transfer::transfer;
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+
+/// Lottery as our contracts draw it: one ticket per holder, uniformly.
+fn draw_winner(tickets: &[Address], r: &Random, ctx: &mut TxContext) -> Address {
+    let mut generator = random::new_generator(r, ctx);
+    let i = random::generate_u64_in_range(&mut generator, 0, tickets.len() as u64 - 1);
+    tickets[i as usize]
+}
+
+//////////////////// RANDOM
+
+#[kani::proof]
+fn the_winner_is_always_a_ticket_holder() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    random::create_for_testing(&mut ctx);
+    let tickets: Vec<Address> = vec![kani::any(), kani::any(), kani::any()];
+
+    let r = transfer::take_shared::<Random>();
+    let winner = draw_winner(&tickets, &r, &mut ctx);
+    assert!(tickets.contains(&winner));
+    transfer::return_object(r);
+}
+
+#[kani::proof]
+fn shuffle_is_a_permutation() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    random::create_for_testing(&mut ctx);
+    let r = transfer::take_shared::<Random>();
+    let mut generator = random::new_generator(&r, &mut ctx);
+
+    let mut v = vec![0u8, 1, 2, 3];
+    random::shuffle(&mut generator, &mut v);
+    let mut sorted = v.clone();
+    sorted.sort();
+    assert!(sorted == vec![0, 1, 2, 3]);
+    transfer::return_object(r);
+}
+
+#[kani::proof]
+fn generated_values_stay_in_range() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    random::create_for_testing(&mut ctx);
+    let r = transfer::take_shared::<Random>();
+    let mut generator = random::new_generator(&r, &mut ctx);
+    let min: u8 = kani::any();
+    let max: u8 = kani::any();
+    kani::assume(min <= max);
+
+    let value = random::generate_u8_in_range(&mut generator, min, max);
+    assert!(min <= value && value <= max);
+    transfer::return_object(r);
+}
+
+#[kani::proof]
+fn an_empty_range_aborts() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    random::create_for_testing(&mut ctx);
+    let r = transfer::take_shared::<Random>();
+    let mut generator = random::new_generator(&r, &mut ctx);
+    let min: u64 = kani::any();
+    let max: u64 = kani::any();
+    kani::assume(min > max);
+
+    abort::expect("random", "generate_u64_in_range", random::EInvalidRange);
+    random::generate_u64_in_range(&mut generator, min, max);
+    abort::assert_aborted();
+}
@@ -1,37 +1,45 @@
-use crate::example_contracts::simple_warrior::simple_warrior::simple_warrior__example;
+use crate::example_contracts::simple_warrior::simple_warrior::{self, simple_warrior__example};
+use crate::runtime::abort;
+use crate::sui_std::tx_context::tx_context;
 
 //////////////////// SIMPLE WARRIOR
 
 #[kani::proof]
 fn try_warrior_succeeds() {
-    let mut w = simple_warrior__example::new_warrior();
-    let mut s = simple_warrior__example::new_sword(1);
+    let mut ctx = tx_context::dummy();
+    let mut w = simple_warrior__example::new_warrior(&mut ctx);
+    let mut s = simple_warrior__example::new_sword(1, &mut ctx);
     simple_warrior__example::equip(&mut w, s);
     simple_warrior__example::unequip(&mut w);
 }
 
 #[kani::proof]
-#[kani::should_panic]
 fn try_warrior_unequips_empty() {
-    let mut w = simple_warrior__example::new_warrior();
-    let mut s = simple_warrior__example::new_sword(1);
+    let mut ctx = tx_context::dummy();
+    let mut w = simple_warrior__example::new_warrior(&mut ctx);
+    let mut s = simple_warrior__example::new_sword(1, &mut ctx);
+    abort::expect("example", "unequip", simple_warrior::ENotEquipped);
     simple_warrior__example::unequip(&mut w);
+    abort::assert_aborted();
 }
 
 #[kani::proof]
-#[kani::should_panic]
 fn try_warrior_equips_twice() {
-    let mut w = simple_warrior__example::new_warrior();
-    let mut s1 = simple_warrior__example::new_sword(1);
-    let mut s2 = simple_warrior__example::new_sword(1);
+    let mut ctx = tx_context::dummy();
+    let mut w = simple_warrior__example::new_warrior(&mut ctx);
+    let mut s1 = simple_warrior__example::new_sword(1, &mut ctx);
+    let mut s2 = simple_warrior__example::new_sword(1, &mut ctx);
     simple_warrior__example::equip(&mut w, s1);
+    abort::expect("example", "equip", simple_warrior::EAlreadyEquipped);
     simple_warrior__example::equip(&mut w, s2);
+    abort::assert_aborted();
 }
 
 #[kani::proof]
 #[kani::unwind(5)]
 fn try_generic() {
-    let mut w = simple_warrior__example::new_warrior();
+    let mut ctx = tx_context::dummy();
+    let mut w = simple_warrior__example::new_warrior(&mut ctx);
     let mut equip: bool = false;
     while true {
         let x: u8 = kani::any();
@@ -39,7 +47,7 @@ fn try_generic() {
         if x == 0 {
             if !equip {
                 equip = true;
-                let mut s1 = simple_warrior__example::new_sword(1);
+                let mut s1 = simple_warrior__example::new_sword(1, &mut ctx);
                 simple_warrior__example::equip(&mut w, s1);
             }
         }
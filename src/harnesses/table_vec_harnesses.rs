@@ -0,0 +1,61 @@
+use crate::runtime::abort;
+use crate::sui_std::table_vec::table_vec::{self, TableVec};
+use crate::sui_std::tx_context::tx_context;
+
+//////////////////// TABLE VEC
+
+#[kani::proof]
+fn history_is_append_only_and_indexed_in_order() {
+    let mut ctx = tx_context::dummy();
+    let first: u64 = kani::any();
+    let second: u64 = kani::any();
+    let mut history = table_vec::singleton(first, &mut ctx);
+    table_vec::push_back(&mut history, second);
+
+    assert!(table_vec::length(&history) == 2);
+    assert!(*table_vec::borrow(&history, 0) == first);
+    assert!(*table_vec::borrow(&history, 1) == second);
+    assert!(table_vec::pop_back(&mut history) == second);
+    assert!(table_vec::pop_back(&mut history) == first);
+    table_vec::destroy_empty(history);
+}
+
+#[kani::proof]
+fn swap_remove_moves_the_last_element_into_place() {
+    let mut ctx = tx_context::dummy();
+    let mut v = table_vec::empty(&mut ctx);
+    for e in [10u8, 20, 30] {
+        table_vec::push_back(&mut v, e);
+    }
+    let i: u64 = kani::any_where(|i: &u64| *i < 3);
+
+    let removed = table_vec::swap_remove(&mut v, i);
+    assert!(removed == [10, 20, 30][i as usize]);
+    assert!(table_vec::length(&v) == 2);
+    if i < 2 {
+        assert!(*table_vec::borrow(&v, i) == 30);
+    }
+    table_vec::drop(v);
+}
+
+#[kani::proof]
+fn borrowing_past_the_end_aborts() {
+    let mut ctx = tx_context::dummy();
+    let v: TableVec<bool> = table_vec::singleton(true, &mut ctx);
+    let i: u64 = kani::any();
+    kani::assume(i >= 1);
+
+    abort::expect("table_vec", "borrow", table_vec::EIndexOutOfBound);
+    table_vec::borrow(&v, i);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn destroying_a_non_empty_vector_aborts() {
+    let mut ctx = tx_context::dummy();
+    let v = table_vec::singleton(0u64, &mut ctx);
+
+    abort::expect("table_vec", "destroy_empty", table_vec::ETableNonEmpty);
+    table_vec::destroy_empty(v);
+    abort::assert_aborted();
+}
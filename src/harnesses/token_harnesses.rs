@@ -0,0 +1,123 @@
+use crate::runtime::{self, abort};
+use crate::sui_std::address::address::Address;
+use crate::sui_std::coin::coin::{self, TreasuryCap};
+use crate::sui_std::token::token::{self, Token, TokenPolicy, TokenPolicyCap};
+use crate::sui_std::transfer::transfer;
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+
+struct WITNESS {}
+
+/// Two independent rules; a spend is only good once both have stamped it.
+struct RuleA {}
+struct RuleB {}
+
+fn setup(ctx: &mut TxContext) -> (TreasuryCap<WITNESS>, TokenPolicy<WITNESS>, TokenPolicyCap<WITNESS>) {
+    let (cap, _metadata) = coin::create_currency(WITNESS {}, 6, vec![], vec![], vec![], None, ctx);
+    let (policy, policy_cap) = token::new_policy(&cap, ctx);
+    (cap, policy, policy_cap)
+}
+
+//////////////////// TOKEN
+
+#[kani::proof]
+fn no_spend_is_confirmed_without_every_rule_stamp() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    let (mut cap, mut policy, policy_cap) = setup(&mut ctx);
+    token::add_rule_for_action::<WITNESS, RuleA>(&mut policy, &policy_cap, token::spend_action(), &mut ctx);
+    token::add_rule_for_action::<WITNESS, RuleB>(&mut policy, &policy_cap, token::spend_action(), &mut ctx);
+
+    let amount: u64 = kani::any();
+    kani::assume(amount < u64::MAX);
+    let t = token::mint(&mut cap, amount, &mut ctx);
+    let mut request = token::spend(t, &mut ctx);
+
+    let stamped_a: bool = kani::any();
+    let stamped_b: bool = kani::any();
+    if stamped_a {
+        token::add_approval(RuleA {}, &mut request, &mut ctx);
+    }
+    if stamped_b {
+        token::add_approval(RuleB {}, &mut request, &mut ctx);
+    }
+
+    if !(stamped_a && stamped_b) {
+        abort::expect("token", "confirm_request", token::ENotApproved);
+        let _ = token::confirm_request_mut(&mut policy, request, &mut ctx);
+        abort::assert_aborted();
+    } else {
+        let (name, spent, _sender, _recipient) = token::confirm_request_mut(&mut policy, request, &mut ctx);
+        assert!(name == token::spend_action() && spent == amount);
+        assert!(token::spent_balance(&policy) == amount);
+    }
+}
+
+#[kani::proof]
+fn unknown_action_is_never_confirmed() {
+    let mut ctx = tx_context::dummy();
+    let (_cap, policy, _policy_cap) = setup(&mut ctx);
+    let request = token::new_request::<WITNESS>(token::to_coin_action(), kani::any(), None, None, &ctx);
+
+    abort::expect("token", "confirm_request", token::EUnknownAction);
+    let _ = token::confirm_request(&policy, request, &mut ctx);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn spent_balance_cannot_be_confirmed_immutably() {
+    let mut ctx = tx_context::dummy();
+    let (mut cap, mut policy, policy_cap) = setup(&mut ctx);
+    token::allow(&mut policy, &policy_cap, token::spend_action(), &mut ctx);
+    let t = token::mint(&mut cap, 10, &mut ctx);
+    let request = token::spend(t, &mut ctx);
+
+    abort::expect("token", "confirm_request", token::ECantConsumeBalance);
+    let _ = token::confirm_request(&policy, request, &mut ctx);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn flush_burns_the_spent_balance() {
+    let mut ctx = tx_context::dummy();
+    let (mut cap, mut policy, policy_cap) = setup(&mut ctx);
+    token::allow(&mut policy, &policy_cap, token::spend_action(), &mut ctx);
+    let amount: u64 = kani::any();
+    kani::assume(amount < u64::MAX);
+    let t = token::mint(&mut cap, amount, &mut ctx);
+    assert!(coin::total_supply(&cap) == amount);
+
+    let request = token::spend(t, &mut ctx);
+    let _ = token::confirm_request_mut(&mut policy, request, &mut ctx);
+    assert!(coin::total_supply(&cap) == amount);
+
+    assert!(token::flush(&mut policy, &mut cap, &mut ctx) == amount);
+    assert!(token::spent_balance(&policy) == 0);
+    assert!(coin::total_supply(&cap) == 0);
+}
+
+#[kani::proof]
+fn policy_cap_confirms_a_transfer_without_rules() {
+    runtime::reset();
+    let mut ctx = tx_context::dummy();
+    let (mut cap, _policy, policy_cap) = setup(&mut ctx);
+    let recipient: Address = kani::any();
+    let t = token::mint(&mut cap, 5, &mut ctx);
+
+    let request = token::transfer(t, recipient, &mut ctx);
+    let (name, amount, _sender, to) = token::confirm_with_policy_cap(&policy_cap, request, &mut ctx);
+    assert!(name == token::transfer_action() && amount == 5 && to == Some(recipient));
+
+    let received = transfer::take_from_address::<Token<WITNESS>>(recipient);
+    assert!(token::value(&received) == 5);
+}
+
+#[kani::proof]
+fn foreign_policy_cap_cannot_change_rules() {
+    let mut ctx = tx_context::dummy();
+    let (cap, mut policy, _policy_cap) = setup(&mut ctx);
+    let (_other, other_cap) = token::new_policy(&cap, &mut ctx);
+
+    abort::expect("token", "allow", token::ENotAuthorized);
+    token::allow(&mut policy, &other_cap, token::spend_action(), &mut ctx);
+    abort::assert_aborted();
+}
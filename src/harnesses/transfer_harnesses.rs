@@ -0,0 +1,156 @@
+use crate::example_contracts::flashloan::flashloan::{flashloan__flashloan, LoanPool};
+use crate::runtime::{abort, ledger};
+use crate::sui_std::coin::coin::{self, Coin, TreasuryCap};
+use crate::sui_std::object::object::{self, KeyObject, UID};
+use crate::sui_std::sui::sui::SUI;
+use crate::sui_std::transfer::transfer::{self, Owner};
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+
+struct WITNESS {}
+
+struct Vault {
+    id: UID,
+    coin: Coin<SUI>,
+}
+
+impl KeyObject for Vault {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+//////////////////// OWNERSHIP LEDGER
+
+#[kani::proof]
+fn treasury_cap_owned_by_sender_after_init() {
+    let mut ctx: TxContext = kani::any();
+    let (cap, metadata) = coin::create_currency(WITNESS {}, 6, vec![], vec![], vec![], None, &mut ctx);
+    let cap_id = object::id(&cap);
+    transfer::pub_transfer(cap, tx_context::sender(&ctx));
+    transfer::pub_freeze_object(metadata);
+
+    assert!(transfer::owner(&cap_id) == Some(Owner::AddressOwner(tx_context::sender(&ctx))));
+    assert!(transfer::most_recent_id_for_address::<TreasuryCap<WITNESS>>(&ctx.sender()) == Some(cap_id));
+}
+
+#[kani::proof]
+fn loan_pool_is_shared_after_init() {
+    let mut ctx = tx_context::dummy();
+    flashloan__flashloan::init(&mut ctx);
+
+    let pool_id = transfer::most_recent_id_shared::<LoanPool>().unwrap();
+    assert!(transfer::owner(&pool_id) == Some(Owner::Shared));
+}
+
+#[kani::proof]
+fn frozen_object_cannot_be_transferred() {
+    let mut ctx = tx_context::dummy();
+    transfer::pub_freeze_object(coin::zero::<SUI>(&mut ctx));
+
+    let frozen = transfer::take_immutable::<Coin<SUI>>();
+    abort::expect("transfer", "transfer_impl", ledger::EImmutableObjectOperationNotSupported);
+    transfer::pub_transfer(frozen, tx_context::sender(&ctx));
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn taken_object_stored_in_another_is_wrapped() {
+    let mut ctx = tx_context::dummy();
+    transfer::pub_transfer(coin::zero::<SUI>(&mut ctx), tx_context::sender(&ctx));
+
+    let mut ctx = tx_context::dummy();
+    let coin = transfer::take_from_address::<Coin<SUI>>(tx_context::sender(&ctx));
+    let coin_id = object::id(&coin);
+    let vault = Vault { id: object::new(&mut ctx), coin };
+    transfer::transfer(vault, tx_context::sender(&ctx));
+    assert!(transfer::owner(&coin_id) == Some(Owner::Wrapped));
+}
+
+#[kani::proof]
+fn old_object_cannot_be_shared() {
+    let mut ctx = tx_context::dummy();
+    transfer::pub_transfer(coin::zero::<SUI>(&mut ctx), tx_context::sender(&ctx));
+
+    let ctx = tx_context::dummy();
+    let owned = transfer::take_from_address::<Coin<SUI>>(tx_context::sender(&ctx));
+    abort::expect("transfer", "share_object_impl", transfer::ESharedNonNewObject);
+    transfer::pub_share_object(owned);
+    abort::assert_aborted();
+}
+
+//////////////////// RECEIVING
+
+struct Wallet {
+    id: UID,
+}
+
+impl KeyObject for Wallet {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+fn new_wallet(ctx: &mut TxContext) -> Wallet {
+    Wallet { id: object::new(ctx) }
+}
+
+#[kani::proof]
+fn coin_sent_to_wallet_can_be_received() {
+    let mut ctx = tx_context::dummy();
+    let mut wallet = new_wallet(&mut ctx);
+    let amount: u64 = kani::any();
+    let (mut cap, metadata) = coin::create_currency(WITNESS {}, 6, vec![], vec![], vec![], None, &mut ctx);
+    let coin = coin::mint(&mut cap, amount, &mut ctx);
+    let coin_id = object::id(&coin);
+    transfer::pub_transfer(coin, object::id_address(&wallet));
+    assert!(transfer::owner(&coin_id) == Some(Owner::ObjectOwner(object::id(&wallet))));
+
+    let ticket = transfer::most_recent_receiving_ticket::<Coin<WITNESS>>(&object::id(&wallet));
+    let received: Coin<WITNESS> = transfer::pub_receive(&mut wallet.id, ticket);
+    assert!(object::id(&received) == coin_id);
+    assert!(coin::value(&received) == amount);
+    assert!(transfer::owner(&coin_id) == Some(Owner::Wrapped));
+    transfer::pub_transfer(received, tx_context::sender(&ctx));
+    transfer::transfer(wallet, tx_context::sender(&ctx));
+    transfer::pub_transfer(cap, tx_context::sender(&ctx));
+    transfer::pub_freeze_object(metadata);
+}
+
+#[kani::proof]
+fn stale_receiving_ticket_aborts() {
+    let mut ctx = tx_context::dummy();
+    let mut wallet = new_wallet(&mut ctx);
+    transfer::pub_transfer(coin::zero::<SUI>(&mut ctx), object::id_address(&wallet));
+    let stale = transfer::most_recent_receiving_ticket::<Coin<SUI>>(&object::id(&wallet));
+
+    // Receiving and sending it back bumps the version past the ticket.
+    let ticket = transfer::most_recent_receiving_ticket::<Coin<SUI>>(&object::id(&wallet));
+    let received: Coin<SUI> = transfer::pub_receive(&mut wallet.id, ticket);
+    transfer::pub_transfer(received, object::id_address(&wallet));
+
+    abort::expect("transfer", "receive_impl", transfer::EUnableToReceiveObject);
+    let _: Coin<SUI> = transfer::pub_receive(&mut wallet.id, stale);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn receiving_with_wrong_type_aborts() {
+    let mut ctx = tx_context::dummy();
+    let mut wallet = new_wallet(&mut ctx);
+    transfer::pub_transfer(coin::zero::<SUI>(&mut ctx), object::id_address(&wallet));
+    let ticket = transfer::most_recent_receiving_ticket::<Coin<SUI>>(&object::id(&wallet));
+
+    let wrong = transfer::make_receiver::<Wallet>(transfer::receiving_object_id(&ticket), 1);
+    abort::expect("transfer", "receive_impl", transfer::EReceivingObjectTypeMismatch);
+    let _: Wallet = transfer::receive(&mut wallet.id, wrong);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn receiving_through_other_parent_aborts() {
+    let mut ctx = tx_context::dummy();
+    let wallet = new_wallet(&mut ctx);
+    let mut other = new_wallet(&mut ctx);
+    transfer::pub_transfer(coin::zero::<SUI>(&mut ctx), object::id_address(&wallet));
+    let ticket = transfer::most_recent_receiving_ticket::<Coin<SUI>>(&object::id(&wallet));
+
+    abort::expect("transfer", "receive_impl", transfer::EUnableToReceiveObject);
+    let _: Coin<SUI> = transfer::pub_receive(&mut other.id, ticket);
+    abort::assert_aborted();
+}
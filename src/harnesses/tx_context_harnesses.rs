@@ -0,0 +1,24 @@
+use crate::sui_std::object::object;
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+
+//////////////////// TX CONTEXT
+
+#[kani::proof]
+fn fresh_addresses_are_counted_and_distinct() {
+    let mut ctx: TxContext = kani::any();
+    let first = tx_context::fresh_object_address(&mut ctx);
+    let uid = object::new(&mut ctx);
+
+    assert!(tx_context::get_ids_created(&ctx) == 2);
+    assert!(object::uid_to_address(&uid) != first);
+}
+
+#[kani::proof]
+fn epoch_only_moves_forward() {
+    let mut ctx: TxContext = kani::any();
+    let epoch = tx_context::epoch(&ctx);
+    kani::assume(epoch < u64::MAX);
+
+    tx_context::increment_epoch_number(&mut ctx);
+    assert!(ctx.epoch() == epoch + 1);
+}
@@ -0,0 +1,68 @@
+use crate::runtime::abort;
+use crate::sui_std::vec_map::vec_map::{self, VecMap};
+
+//////////////////// VEC MAP
+
+#[kani::proof]
+fn entries_keep_insertion_order() {
+    let a: u64 = kani::any();
+    let b: u64 = kani::any();
+    let c: u64 = kani::any();
+    kani::assume(a != b && a != c && b != c);
+    let mut map: VecMap<u64, u8> = vec_map::empty();
+    vec_map::insert(&mut map, a, 0);
+    vec_map::insert(&mut map, b, 1);
+    vec_map::insert(&mut map, c, 2);
+
+    assert!(vec_map::remove(&mut map, &b) == (b, 1));
+    assert!(vec_map::keys(&map) == vec![a, c]);
+    assert!(vec_map::get_entry_by_idx(&map, 1) == (&c, &2));
+    assert!(vec_map::try_get(&map, &b).is_none());
+    assert!(vec_map::pop(&mut map) == (c, 2));
+    assert!(vec_map::size(&map) == 1);
+}
+
+#[kani::proof]
+fn keys_and_values_round_trip() {
+    let keys: Vec<u8> = vec![kani::any(), kani::any()];
+    kani::assume(keys[0] != keys[1]);
+    let values: Vec<bool> = vec![kani::any(), kani::any()];
+
+    let map = vec_map::from_keys_values(keys.clone(), values.clone());
+    assert!(*vec_map::get(&map, &keys[1]) == values[1]);
+    assert!(vec_map::into_keys_values(map) == (keys, values));
+}
+
+#[kani::proof]
+fn inserting_a_key_twice_aborts() {
+    let key: u64 = kani::any();
+    let mut map = vec_map::empty();
+    vec_map::insert(&mut map, key, 0u8);
+
+    abort::expect("vec_map", "insert", vec_map::EKeyAlreadyExists);
+    vec_map::insert(&mut map, key, 1u8);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn getting_a_missing_key_aborts() {
+    let mut map = vec_map::empty();
+    vec_map::insert(&mut map, 0u64, 0u8);
+    let key: u64 = kani::any();
+    kani::assume(key != 0);
+
+    abort::expect("vec_map", "get_idx", vec_map::EKeyDoesNotExist);
+    vec_map::get(&map, &key);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn indexing_past_the_end_aborts() {
+    let map: VecMap<u64, u8> = vec_map::from_keys_values(vec![0, 1], vec![0, 1]);
+    let idx: u64 = kani::any();
+    kani::assume(idx >= 2);
+
+    abort::expect("vec_map", "get_entry_by_idx", vec_map::EIndexOutOfBounds);
+    vec_map::get_entry_by_idx(&map, idx);
+    abort::assert_aborted();
+}
@@ -0,0 +1,63 @@
+use crate::runtime::abort;
+use crate::sui_std::address::address::Address;
+use crate::sui_std::vec_set::vec_set::{self, VecSet, MAX_ARBITRARY_SIZE};
+
+/// Allowlist check as our access-control modules write it.
+fn authorized(allowlist: &VecSet<Address>, sender: &Address) -> bool {
+    vec_set::contains(allowlist, sender)
+}
+
+//////////////////// VEC SET
+
+#[kani::proof]
+fn arbitrary_sets_are_bounded_and_distinct() {
+    let set: VecSet<Address> = kani::any();
+    let keys = vec_set::keys(&set);
+    assert!(vec_set::size(&set) <= MAX_ARBITRARY_SIZE);
+    for i in 0..keys.len() {
+        for j in 0..i {
+            assert!(keys[i] != keys[j]);
+        }
+    }
+}
+
+#[kani::proof]
+fn revoked_sender_is_no_longer_authorized() {
+    let mut allowlist: VecSet<Address> = kani::any();
+    let sender: Address = kani::any();
+    kani::assume(authorized(&allowlist, &sender));
+    let size = vec_set::size(&allowlist);
+
+    vec_set::remove(&mut allowlist, &sender);
+    assert!(!authorized(&allowlist, &sender));
+    assert!(vec_set::size(&allowlist) == size - 1);
+}
+
+#[kani::proof]
+fn keys_round_trip_in_insertion_order() {
+    let set: VecSet<u8> = kani::any();
+    let keys = vec_set::into_keys(set.clone());
+    assert!(vec_set::from_keys(keys) == set);
+}
+
+#[kani::proof]
+fn inserting_a_member_aborts() {
+    let mut allowlist: VecSet<Address> = kani::any();
+    let sender: Address = kani::any();
+    kani::assume(authorized(&allowlist, &sender));
+
+    abort::expect("vec_set", "insert", vec_set::EKeyAlreadyExists);
+    vec_set::insert(&mut allowlist, sender);
+    abort::assert_aborted();
+}
+
+#[kani::proof]
+fn removing_a_non_member_aborts() {
+    let mut allowlist = vec_set::singleton(Address::from_u128(0xA));
+    let sender: Address = kani::any();
+    kani::assume(!authorized(&allowlist, &sender));
+
+    abort::expect("vec_set", "get_idx", vec_set::EKeyDoesNotExist);
+    vec_set::remove(&mut allowlist, &sender);
+    abort::assert_aborted();
+}
@@ -1,5 +1,6 @@
 mod example_contracts;
 mod sui_std;
+mod runtime;
 mod harnesses;
 
 
@@ -0,0 +1,124 @@
+use std::cell::RefCell;
+use std::fmt;
+
+use crate::runtime::events;
+
+/// A Move abort: the `code` an `assert!` or `abort` in `module::function`
+/// stopped the transaction with.
+///
+/// sui_std and translated modules never panic with a bare message for a Move
+/// abort, they go through `raise`, so harnesses can tell which abort happened.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MoveAbort {
+    pub module: &'static str,
+    pub function: &'static str,
+    pub code: u64,
+}
+
+impl fmt::Display for MoveAbort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{} aborted with code {}", self.module, self.function, self.code)
+    }
+}
+
+/// What the running harness declared about aborts, see `expect` and `forbid`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Expectation {
+    /// Every abort is a failure.
+    None,
+    /// This abort is the expected outcome, any other is a failure.
+    Abort(MoveAbort),
+    /// Aborts with this code from this module are failures, any other just
+    /// rejects the transaction.
+    Never(&'static str, u64),
+    /// Every abort just rejects the transaction.
+    Any,
+}
+
+thread_local! {
+    static EXPECTATION: RefCell<Expectation> = const { RefCell::new(Expectation::None) };
+}
+
+/// Forget what the previous harness expected.
+pub fn reset() {
+    EXPECTATION.with(|e| *e.borrow_mut() = Expectation::None);
+}
+
+/// Abort the transaction with `code` from `module::function`.
+///
+/// Concretely this unwinds with the `MoveAbort` as payload, see `catch`. Kani
+/// can't unwind: an abort the harness asked for with `expect`, or one it didn't
+/// `forbid`, ends the path successfully, every other abort fails the proof.
+pub fn raise(module: &'static str, function: &'static str, code: u64) -> ! {
+    let abort = MoveAbort { module, function, code };
+    events::reset();
+    end_transaction(abort)
+}
+
+/// Abort with `code` from `module::function` unless `cond` holds, like Move's `assert!`.
+pub fn check(cond: bool, module: &'static str, function: &'static str, code: u64) {
+    if !cond {
+        raise(module, function, code);
+    }
+}
+
+#[cfg(not(kani))]
+fn end_transaction(abort: MoveAbort) -> ! {
+    std::panic::panic_any(abort)
+}
+
+#[cfg(kani)]
+fn end_transaction(abort: MoveAbort) -> ! {
+    match EXPECTATION.with(|e| *e.borrow()) {
+        Expectation::Abort(expected) if expected == abort => {}
+        Expectation::Never(module, code) if (module, code) != (abort.module, abort.code) => {}
+        Expectation::Any => {}
+        _ => panic!("{}", abort),
+    }
+    kani::assume(false);
+    unreachable!()
+}
+
+/// Run `f`, returning the abort it raised instead of unwinding.
+#[cfg(not(kani))]
+pub fn catch<R>(f: impl FnOnce() -> R) -> Result<R, MoveAbort> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).map_err(|payload| match payload.downcast::<MoveAbort>() {
+        Ok(abort) => *abort,
+        Err(payload) => std::panic::resume_unwind(payload),
+    })
+}
+
+// === verification ===
+
+/// The rest of the harness must abort with `code` from `module::function`.
+/// Finish the harness with `assert_aborted`, so paths that don't abort fail.
+#[cfg(kani)]
+pub fn expect(module: &'static str, function: &'static str, code: u64) {
+    EXPECTATION.with(|e| *e.borrow_mut() = Expectation::Abort(MoveAbort { module, function, code }));
+}
+
+/// The rest of the harness must never abort with `code` from any function of `module`,
+/// e.g. an invariant checked all over the module. Paths that abort with anything else
+/// are rejected transactions and are not checked further.
+#[cfg(kani)]
+pub fn forbid(module: &'static str, code: u64) {
+    EXPECTATION.with(|e| *e.borrow_mut() = Expectation::Never(module, code));
+}
+
+/// Aborts in the rest of the harness just reject the transaction, for properties
+/// of successful transactions only. Paths that abort are not checked further.
+#[cfg(kani)]
+pub fn allow() {
+    EXPECTATION.with(|e| *e.borrow_mut() = Expectation::Any);
+}
+
+/// Fails every path that reaches it: paths that raised the abort given to
+/// `expect` have already ended.
+#[cfg(kani)]
+pub fn assert_aborted() {
+    let expected = EXPECTATION.with(|e| *e.borrow());
+    match expected {
+        Expectation::Abort(abort) => panic!("expected {}, but it did not abort", abort),
+        _ => panic!("no abort was expected with abort::expect"),
+    }
+}
@@ -0,0 +1,122 @@
+//! Move's integer arithmetic. Overflow, underflow, division by zero and
+//! shifting by the bit width or more all abort the transaction with the VM's
+//! `ARITHMETIC_ERROR`, in every build and under kani, instead of wrapping or
+//! tripping a generic overflow check.
+//!
+//! Translated code calls `add(a, b)` where Move wrote `a + b`, `cast::<_, u8>(a)`
+//! where it wrote `(a as u8)`, and so on.
+
+use crate::runtime::abort;
+use crate::runtime::u256::U256;
+
+/// Status code the Move VM aborts with on arithmetic errors.
+pub const ARITHMETIC_ERROR: u64 = 4017;
+
+/// Arithmetic aborts are raised by the VM, not by a module.
+pub const VM: &str = "vm";
+
+/// Move's unsigned integer types: `u8`, `u16`, `u32`, `u64`, `u128` and `u256`.
+pub trait MoveInt: Copy + Ord {
+    const BITS: u32;
+
+    /// Widen to `u256`, which holds every Move integer.
+    fn to_u256(self) -> U256;
+    /// Narrow from `u256`, `None` if `value` does not fit.
+    fn from_u256(value: U256) -> Option<Self>;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    /// Shift left by less than `BITS`, bits shifted out are lost.
+    fn shl(self, amount: u8) -> Self;
+    /// Shift right by less than `BITS`.
+    fn shr(self, amount: u8) -> Self;
+}
+
+macro_rules! move_int {
+    ($($t:ty),*) => {
+        $(
+            impl MoveInt for $t {
+                const BITS: u32 = <$t>::BITS;
+
+                fn to_u256(self) -> U256 { U256::from_u128(self as u128) }
+                fn from_u256(value: U256) -> Option<Self> { value.to_u128().and_then(|v| <$t>::try_from(v).ok()) }
+
+                fn checked_add(self, other: Self) -> Option<Self> { <$t>::checked_add(self, other) }
+                fn checked_sub(self, other: Self) -> Option<Self> { <$t>::checked_sub(self, other) }
+                fn checked_mul(self, other: Self) -> Option<Self> { <$t>::checked_mul(self, other) }
+                fn checked_div(self, other: Self) -> Option<Self> { <$t>::checked_div(self, other) }
+                fn checked_rem(self, other: Self) -> Option<Self> { <$t>::checked_rem(self, other) }
+                fn shl(self, amount: u8) -> Self { self << amount }
+                fn shr(self, amount: u8) -> Self { self >> amount }
+            }
+        )*
+    };
+}
+
+move_int!(u8, u16, u32, u64, u128);
+
+impl MoveInt for U256 {
+    const BITS: u32 = 256;
+
+    fn to_u256(self) -> U256 { self }
+    fn from_u256(value: U256) -> Option<Self> { Some(value) }
+    fn checked_add(self, other: Self) -> Option<Self> { U256::checked_add(self, other) }
+    fn checked_sub(self, other: Self) -> Option<Self> { U256::checked_sub(self, other) }
+    fn checked_mul(self, other: Self) -> Option<Self> { U256::checked_mul(self, other) }
+    fn checked_div(self, other: Self) -> Option<Self> { U256::checked_div(self, other) }
+    fn checked_rem(self, other: Self) -> Option<Self> { U256::checked_rem(self, other) }
+    fn shl(self, amount: u8) -> Self { U256::shl(self, amount) }
+    fn shr(self, amount: u8) -> Self { U256::shr(self, amount) }
+}
+
+fn or_abort<T>(result: Option<T>, op: &'static str) -> T {
+    match result {
+        Some(value) => value,
+        None => abort::raise(VM, op, ARITHMETIC_ERROR),
+    }
+}
+
+/// `a + b`, aborts on overflow.
+pub fn add<T: MoveInt>(a: T, b: T) -> T {
+    or_abort(a.checked_add(b), "add")
+}
+
+/// `a - b`, aborts on underflow.
+pub fn sub<T: MoveInt>(a: T, b: T) -> T {
+    or_abort(a.checked_sub(b), "sub")
+}
+
+/// `a * b`, aborts on overflow.
+pub fn mul<T: MoveInt>(a: T, b: T) -> T {
+    or_abort(a.checked_mul(b), "mul")
+}
+
+/// `a / b`, aborts if `b` is zero.
+pub fn div<T: MoveInt>(a: T, b: T) -> T {
+    or_abort(a.checked_div(b), "div")
+}
+
+/// `a % b`, aborts if `b` is zero.
+pub fn rem<T: MoveInt>(a: T, b: T) -> T {
+    or_abort(a.checked_rem(b), "mod")
+}
+
+/// `a << amount`, aborts if `amount` is not less than the bit width of `T`.
+pub fn shl<T: MoveInt>(a: T, amount: u8) -> T {
+    abort::check((amount as u32) < T::BITS, VM, "shl", ARITHMETIC_ERROR);
+    a.shl(amount)
+}
+
+/// `a >> amount`, aborts if `amount` is not less than the bit width of `T`.
+pub fn shr<T: MoveInt>(a: T, amount: u8) -> T {
+    abort::check((amount as u32) < T::BITS, VM, "shr", ARITHMETIC_ERROR);
+    a.shr(amount)
+}
+
+/// `(value as U)`, aborts if `value` does not fit in `U`.
+pub fn cast<T: MoveInt, U: MoveInt>(value: T) -> U {
+    or_abort(U::from_u256(value.to_u256()), "cast")
+}
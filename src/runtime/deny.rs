@@ -0,0 +1,118 @@
+use std::any::TypeId;
+use std::cell::RefCell;
+
+use crate::sui_std::address::address::Address;
+
+/// The address is on the deny list of the coin's type. Not a Move abort code: validators
+/// reject the transaction, here taking or transferring the coin does.
+pub const EAddressDeniedForCoin: u64 = 6;
+
+/// The deny lists of regulated coins, and the epoch they are read at.
+///
+/// On chain `DenyList` is a shared system object, but it is validators that
+/// enforce it when they load transaction inputs and write outputs, not Move
+/// code. Its contents live here, next to the ledger, so `transfer` can consult
+/// them; `sui_std::deny_list` is the handle Move code updates them through.
+///
+/// Every entry is a setting whose changes take effect at the next epoch
+/// boundary: reads for the current epoch see the value it had before any
+/// change made during the epoch, reads for the next epoch see the latest one.
+struct Deny {
+    /// Epoch of the transaction currently executing, see `set_epoch`.
+    epoch: u64,
+    /// Settings by `(per_type_index, per_type_key, key)`.
+    settings: Vec<((u64, TypeId, Key), Setting)>,
+}
+
+/// What a setting is about, for one list.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Key {
+    /// Whether the address is denied.
+    Address(Address),
+    /// Whether every address is denied.
+    GlobalPause,
+}
+
+/// `sui::config::Setting<bool>`, with an absent value read as `false`.
+#[derive(Clone, Copy)]
+struct Setting {
+    newer_value_epoch: u64,
+    newer_value: bool,
+    older_value: bool,
+}
+
+thread_local! {
+    static DENY: RefCell<Deny> = const { RefCell::new(Deny { epoch: 0, settings: Vec::new() }) };
+}
+
+/// Forget every list, and go back to epoch 0.
+pub fn reset() {
+    DENY.with(|d| {
+        let mut d = d.borrow_mut();
+        d.epoch = 0;
+        d.settings.clear();
+    });
+}
+
+/// Run the following transactions in `epoch`.
+pub fn set_epoch(epoch: u64) {
+    DENY.with(|d| d.borrow_mut().epoch = epoch);
+}
+
+/// Set `key` of the list `(index, coin)` to `value` from the next epoch on.
+pub fn set_for_next_epoch(index: u64, coin: TypeId, key: Key, value: bool) {
+    DENY.with(|d| {
+        let mut d = d.borrow_mut();
+        let epoch = d.epoch;
+        match d.settings.iter_mut().find(|(k, _)| *k == (index, coin, key)) {
+            Some((_, setting)) if setting.newer_value_epoch == epoch => setting.newer_value = value,
+            Some((_, setting)) => {
+                setting.older_value = setting.newer_value;
+                setting.newer_value = value;
+                setting.newer_value_epoch = epoch;
+            }
+            // Removing what was never there leaves no setting behind.
+            None if !value => {}
+            None => d.settings.push((
+                (index, coin, key),
+                Setting { newer_value_epoch: epoch, newer_value: value, older_value: false },
+            )),
+        }
+    });
+}
+
+/// Value of `key` in the list `(index, coin)` for the current epoch.
+pub fn read_current_epoch(index: u64, coin: TypeId, key: Key) -> bool {
+    DENY.with(|d| {
+        let d = d.borrow();
+        match d.settings.iter().find(|(k, _)| *k == (index, coin, key)) {
+            Some((_, setting)) if setting.newer_value_epoch == d.epoch => setting.older_value,
+            Some((_, setting)) => setting.newer_value,
+            None => false,
+        }
+    })
+}
+
+/// Value of `key` in the list `(index, coin)` from the next epoch on.
+pub fn read_next_epoch(index: u64, coin: TypeId, key: Key) -> bool {
+    DENY.with(|d| {
+        let d = d.borrow();
+        d.settings.iter().find(|(k, _)| *k == (index, coin, key)).is_some_and(|(_, s)| s.newer_value)
+    })
+}
+
+/// Coin lists use this index in `sui::coin`.
+pub const COIN_INDEX: u64 = 0;
+
+/// Whether `account` can no longer use coins of type `coin` as transaction
+/// inputs. Takes effect immediately.
+pub fn blocks_input(coin: TypeId, account: Address) -> bool {
+    read_next_epoch(COIN_INDEX, coin, Key::Address(account)) || read_next_epoch(COIN_INDEX, coin, Key::GlobalPause)
+}
+
+/// Whether `account` can no longer receive coins of type `coin`. Takes effect
+/// at the next epoch boundary.
+pub fn blocks_receipt(coin: TypeId, account: Address) -> bool {
+    read_current_epoch(COIN_INDEX, coin, Key::Address(account))
+        || read_current_epoch(COIN_INDEX, coin, Key::GlobalPause)
+}
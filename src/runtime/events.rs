@@ -0,0 +1,31 @@
+use std::any::Any;
+use std::cell::RefCell;
+
+thread_local! {
+    /// Events emitted by the transaction currently executing, oldest first.
+    ///
+    /// A new transaction starts with an empty log, and an abort drops whatever the
+    /// aborted transaction emitted, as on chain where only effects of successful
+    /// transactions carry events.
+    static EVENTS: RefCell<Vec<Box<dyn Any>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Forget every event, e.g. when a new transaction starts or one aborts.
+pub fn reset() {
+    EVENTS.with(|e| e.borrow_mut().clear());
+}
+
+/// Append `event` to the log.
+pub fn record(event: Box<dyn Any>) {
+    EVENTS.with(|e| e.borrow_mut().push(event));
+}
+
+/// Number of events in the log, of any type.
+pub fn count() -> usize {
+    EVENTS.with(|e| e.borrow().len())
+}
+
+/// The events of type `T` in the log, in the order they were emitted.
+pub fn by_type<T: Clone + 'static>() -> Vec<T> {
+    EVENTS.with(|e| e.borrow().iter().filter_map(|event| event.downcast_ref::<T>()).cloned().collect())
+}
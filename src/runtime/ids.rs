@@ -0,0 +1,115 @@
+use std::cell::RefCell;
+
+/// The single source of object IDs for sui_std and every translated module.
+///
+/// Allocated IDs are kept together with whether the object they name is still
+/// alive and the transaction that created it, so harnesses can ask about deleted
+/// and live objects, and `transfer` can tell new objects apart. There is no lock:
+/// harnesses are single threaded, and CBMC would otherwise have to model a mutex
+/// on every object creation.
+///
+/// Object IDs start at `FIRST_OBJECT_ID`. Everything below is left to account
+/// addresses and well-known system objects, so an account never shares its
+/// address with an object, as on chain where object IDs are hashes.
+struct IdAllocator {
+    /// Last ID handed out by the concrete allocator.
+    last: u64,
+    /// Transaction currently executing, see `begin_transaction`.
+    tx: u64,
+    /// Every ID allocated since the last `reset`, with its liveness and the
+    /// transaction it was created in.
+    allocated: Vec<(u64, bool, u64)>,
+}
+
+/// Lowest ID `fresh_id` hands out.
+pub const FIRST_OBJECT_ID: u64 = 1 << 32;
+
+thread_local! {
+    static ALLOCATOR: RefCell<IdAllocator> = const {
+        RefCell::new(IdAllocator {
+            last: FIRST_OBJECT_ID - 1,
+            tx: 0,
+            allocated: Vec::new(),
+        })
+    };
+}
+
+/// Forget every allocated ID. Call at the start of a harness that runs
+/// after others on the same thread.
+pub fn reset() {
+    ALLOCATOR.with(|a| {
+        let mut a = a.borrow_mut();
+        a.last = FIRST_OBJECT_ID - 1;
+        a.tx = 0;
+        a.allocated.clear();
+    });
+}
+
+/// Start a new transaction. Objects allocated before it are no longer new.
+pub fn begin_transaction() {
+    ALLOCATOR.with(|a| a.borrow_mut().tx += 1);
+}
+
+/// Hand out an ID that was never handed out before, live or deleted.
+///
+/// Concretely IDs are a counter. Under kani the ID is nondeterministic and
+/// only assumed distinct from all the previous ones, so proofs don't depend
+/// on allocation order.
+pub fn fresh_id() -> u64 {
+    ALLOCATOR.with(|a| {
+        let mut a = a.borrow_mut();
+        let id = next_id(&mut a);
+        let tx = a.tx;
+        a.allocated.push((id, true, tx));
+        id
+    })
+}
+
+#[cfg(not(kani))]
+fn next_id(a: &mut IdAllocator) -> u64 {
+    a.last = a.last.checked_add(1).expect("object ID space exhausted");
+    a.last
+}
+
+#[cfg(kani)]
+fn next_id(a: &mut IdAllocator) -> u64 {
+    let id: u64 = kani::any();
+    kani::assume(id >= FIRST_OBJECT_ID);
+    for (other, _, _) in a.allocated.iter() {
+        kani::assume(id != *other);
+    }
+    id
+}
+
+/// Mark `id` as deleted. It will never be handed out again.
+pub fn delete_id(id: u64) {
+    ALLOCATOR.with(|a| {
+        let mut a = a.borrow_mut();
+        let entry = a.allocated.iter_mut().find(|(other, _, _)| *other == id);
+        match entry {
+            Some((_, live, _)) => {
+                assert!(*live, "object {} deleted twice", id);
+                *live = false;
+            }
+            None => panic!("object {} was never allocated", id),
+        }
+    });
+}
+
+/// Whether `id` was allocated and not deleted since.
+pub fn is_live(id: u64) -> bool {
+    ALLOCATOR.with(|a| a.borrow().allocated.iter().any(|(other, live, _)| *other == id && *live))
+}
+
+/// Whether `id` was allocated in the transaction currently executing.
+pub fn is_new(id: u64) -> bool {
+    ALLOCATOR.with(|a| {
+        let a = a.borrow();
+        a.allocated.iter().any(|(other, _, tx)| *other == id && *tx == a.tx)
+    })
+}
+
+/// Number of objects currently alive.
+pub fn live_count() -> usize {
+    ALLOCATOR.with(|a| a.borrow().allocated.iter().filter(|(_, live, _)| *live).count())
+}
@@ -0,0 +1,142 @@
+use std::any::Any;
+use std::cell::RefCell;
+
+use crate::sui_std::address::address::Address;
+use crate::sui_std::object::object::ID;
+
+/// Frozen objects can only be read. Not a Move abort code: on chain the transaction is rejected
+/// before it runs, here the ledger rejects the transfer or share instead.
+pub const EImmutableObjectOperationNotSupported: u64 = 5;
+
+/// Who an object in storage belongs to, as Sui tracks it for every object.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Owner {
+    /// Owned by an address, e.g. after `transfer::transfer`.
+    AddressOwner(Address),
+    /// Owned by another object, e.g. when transferred to that object's ID.
+    ObjectOwner(ID),
+    /// Shared with `transfer::share_object`, anyone can use it mutably.
+    Shared,
+    /// Frozen with `transfer::freeze_object`, anyone can read it.
+    Immutable,
+    /// Alive, but stored inside the fields of another object instead of on
+    /// its own. Never recorded, `owner` reports it for live unrecorded IDs.
+    Wrapped,
+}
+
+/// One object in storage. `object` is `None` while a harness has taken the
+/// object out to use it, then `taken` is set, or while it travels inside its
+/// parent as a dynamic object field; the owner stays recorded until it is put
+/// back or moved elsewhere. Objects that leave storage keep their entry with
+/// no owner, so their version keeps growing if they come back.
+struct Entry {
+    id: ID,
+    owner: Option<Owner>,
+    version: u64,
+    object: Option<Box<dyn Any>>,
+    taken: bool,
+}
+
+thread_local! {
+    static LEDGER: RefCell<Vec<Entry>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Forget every stored object.
+pub fn reset() {
+    LEDGER.with(|l| l.borrow_mut().clear());
+}
+
+/// Record `object` under `owner`, bumping its version. Objects seen for the
+/// first time start at version 1. The object becomes the most recent one.
+pub fn store(id: ID, owner: Owner, object: Box<dyn Any>) {
+    record(id, owner, Some(object));
+}
+
+/// Record that `id` belongs to `owner` while the object itself is held
+/// elsewhere, e.g. by its parent as a dynamic object field. Bumps its version
+/// like `store`, but nothing can take the object out of storage.
+pub fn attach(id: ID, owner: Owner) {
+    record(id, owner, None);
+}
+
+fn record(id: ID, owner: Owner, object: Option<Box<dyn Any>>) {
+    LEDGER.with(|l| {
+        let mut l = l.borrow_mut();
+        let version = match l.iter().position(|e| e.id == id) {
+            Some(i) => l.remove(i).version + 1,
+            None => 1,
+        };
+        l.push(Entry { id, owner: Some(owner), version, object, taken: false });
+    });
+}
+
+/// Put back an object previously taken with `take`, keeping owner and version.
+pub fn put_back(id: ID, object: Box<dyn Any>) {
+    LEDGER.with(|l| {
+        let mut l = l.borrow_mut();
+        let e = l.iter_mut().find(|e| e.id == id).expect("object is not in storage");
+        assert!(e.object.is_none(), "object was not taken out of storage");
+        e.object = Some(object);
+        e.taken = false;
+    });
+}
+
+/// Take the object out of storage. Its owner stays recorded.
+pub fn take(id: ID) -> Option<Box<dyn Any>> {
+    LEDGER.with(|l| {
+        let mut l = l.borrow_mut();
+        let e = l.iter_mut().find(|e| e.id == id)?;
+        let object = e.object.take()?;
+        e.taken = true;
+        Some(object)
+    })
+}
+
+/// Take the object out of storage for good, e.g. when it is received by its
+/// parent. Only its version is remembered.
+pub fn remove(id: ID) -> Option<Box<dyn Any>> {
+    LEDGER.with(|l| {
+        let mut l = l.borrow_mut();
+        let e = l.iter_mut().find(|e| e.id == id)?;
+        e.owner = None;
+        e.taken = false;
+        e.object.take()
+    })
+}
+
+/// Drop everything known about `id`, the object was deleted.
+pub fn forget(id: ID) {
+    LEDGER.with(|l| l.borrow_mut().retain(|e| e.id != id));
+}
+
+/// Recorded owner of `id`, if it is in storage.
+pub fn owner(id: ID) -> Option<Owner> {
+    LEDGER.with(|l| l.borrow().iter().find(|e| e.id == id).and_then(|e| e.owner.clone()))
+}
+
+/// Version of `id`, if it is in storage.
+pub fn version(id: ID) -> Option<u64> {
+    LEDGER.with(|l| l.borrow().iter().find(|e| e.id == id && e.owner.is_some()).map(|e| e.version))
+}
+
+/// Whether `id` was taken out with `take` and has not been put back or stored since.
+pub fn is_taken(id: ID) -> bool {
+    LEDGER.with(|l| l.borrow().iter().any(|e| e.id == id && e.taken))
+}
+
+/// Whether `id` is in storage and not currently taken out.
+pub fn is_available(id: ID) -> bool {
+    LEDGER.with(|l| l.borrow().iter().any(|e| e.id == id && e.object.is_some()))
+}
+
+/// Most recently stored object of type `T` whose owner satisfies `pred` and
+/// that is not currently taken out.
+pub fn most_recent<T: Any>(pred: impl Fn(&Owner) -> bool) -> Option<ID> {
+    LEDGER.with(|l| {
+        l.borrow()
+            .iter()
+            .rev()
+            .find(|e| e.owner.as_ref().is_some_and(&pred) && e.object.as_ref().is_some_and(|o| o.is::<T>()))
+            .map(|e| e.id)
+    })
+}
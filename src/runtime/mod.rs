@@ -0,0 +1,23 @@
+pub(crate) mod abort;
+pub(crate) mod arith;
+pub(crate) mod deny;
+pub(crate) mod events;
+pub(crate) mod ids;
+pub(crate) mod ledger;
+pub(crate) mod u256;
+
+/// Reset all runtime state, so that a harness starts from an empty world.
+pub fn reset() {
+    abort::reset();
+    deny::reset();
+    events::reset();
+    ids::reset();
+    ledger::reset();
+}
+
+/// Start a new transaction in `epoch`. Every `TxContext` runs in its own transaction.
+pub fn begin_transaction(epoch: u64) {
+    deny::set_epoch(epoch);
+    events::reset();
+    ids::begin_transaction();
+}
\ No newline at end of file
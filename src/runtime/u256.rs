@@ -0,0 +1,209 @@
+use std::fmt;
+use std::ops::{BitAnd, BitOr, BitXor};
+
+/// Move's `u256`, as two `u128` halves.
+///
+/// Fields are ordered so the derived `Ord` is numeric order. Arithmetic goes
+/// through `runtime::arith` like every other integer width, so it aborts
+/// exactly where Move does. Operations on values that fit in a `u128` stay on
+/// the `u128` fast path, which keeps kani's formulas small for typical values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct U256 {
+    hi: u128,
+    lo: u128,
+}
+
+/// Move spells the type in lower case; translated code keeps doing so.
+#[allow(non_camel_case_types)]
+pub type u256 = U256;
+
+impl U256 {
+    pub const ZERO: U256 = U256 { hi: 0, lo: 0 };
+    pub const ONE: U256 = U256 { hi: 0, lo: 1 };
+    pub const MAX: U256 = U256 { hi: u128::MAX, lo: u128::MAX };
+
+    /// The value `hi * 2^128 + lo`. The transpiler writes `u256` literals this way.
+    pub const fn from_limbs(hi: u128, lo: u128) -> U256 {
+        U256 { hi, lo }
+    }
+
+    pub const fn from_u128(value: u128) -> U256 {
+        U256 { hi: 0, lo: value }
+    }
+
+    /// The value, if it fits in a `u128`.
+    pub fn to_u128(self) -> Option<u128> {
+        if self.hi == 0 { Some(self.lo) } else { None }
+    }
+
+    /// Big-endian bytes, as BCS serializes addresses.
+    pub fn to_be_bytes(self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes[..16].copy_from_slice(&self.hi.to_be_bytes());
+        bytes[16..].copy_from_slice(&self.lo.to_be_bytes());
+        bytes
+    }
+
+    pub fn from_be_bytes(bytes: [u8; 32]) -> U256 {
+        let mut hi = [0u8; 16];
+        let mut lo = [0u8; 16];
+        hi.copy_from_slice(&bytes[..16]);
+        lo.copy_from_slice(&bytes[16..]);
+        U256 { hi: u128::from_be_bytes(hi), lo: u128::from_be_bytes(lo) }
+    }
+
+    pub fn checked_add(self, other: U256) -> Option<U256> {
+        let (lo, carry) = self.lo.overflowing_add(other.lo);
+        let hi = self.hi.checked_add(other.hi)?.checked_add(carry as u128)?;
+        Some(U256 { hi, lo })
+    }
+
+    pub fn checked_sub(self, other: U256) -> Option<U256> {
+        let (lo, borrow) = self.lo.overflowing_sub(other.lo);
+        let hi = self.hi.checked_sub(other.hi)?.checked_sub(borrow as u128)?;
+        Some(U256 { hi, lo })
+    }
+
+    pub fn checked_mul(self, other: U256) -> Option<U256> {
+        if let (Some(a), Some(b)) = (self.to_u128(), other.to_u128()) {
+            if let Some(product) = a.checked_mul(b) {
+                return Some(U256::from_u128(product));
+            }
+        }
+        // Schoolbook multiplication on 64-bit limbs, least significant first.
+        let a = self.limbs();
+        let b = other.limbs();
+        let mut out = [0u64; 8];
+        for i in 0..4 {
+            let mut carry: u128 = 0;
+            for j in 0..4 {
+                let t = (a[i] as u128) * (b[j] as u128) + out[i + j] as u128 + carry;
+                out[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            out[i + 4] = carry as u64;
+        }
+        if out[4..].iter().any(|limb| *limb != 0) {
+            return None;
+        }
+        Some(U256::from_limbs64([out[0], out[1], out[2], out[3]]))
+    }
+
+    pub fn checked_div(self, other: U256) -> Option<U256> {
+        self.div_rem(other).map(|(q, _)| q)
+    }
+
+    pub fn checked_rem(self, other: U256) -> Option<U256> {
+        self.div_rem(other).map(|(_, r)| r)
+    }
+
+    /// Quotient and remainder, `None` when dividing by zero.
+    fn div_rem(self, other: U256) -> Option<(U256, U256)> {
+        if other == U256::ZERO {
+            return None;
+        }
+        if let (Some(a), Some(b)) = (self.to_u128(), other.to_u128()) {
+            return Some((U256::from_u128(a / b), U256::from_u128(a % b)));
+        }
+        // Binary long division.
+        let mut quotient = U256::ZERO;
+        let mut remainder = U256::ZERO;
+        for bit in (0..256u32).rev() {
+            remainder = remainder.shl(1);
+            if self.bit(bit) {
+                remainder.lo |= 1;
+            }
+            if remainder >= other {
+                remainder = remainder.checked_sub(other).unwrap();
+                quotient = quotient | U256::ONE.shl(bit as u8);
+            }
+        }
+        Some((quotient, remainder))
+    }
+
+    /// `self << amount`, bits shifted out are lost. Any `u8` is a valid amount.
+    pub fn shl(self, amount: u8) -> U256 {
+        match amount {
+            0 => self,
+            1..=127 => U256 { hi: (self.hi << amount) | (self.lo >> (128 - amount)), lo: self.lo << amount },
+            _ => U256 { hi: self.lo << (amount - 128), lo: 0 },
+        }
+    }
+
+    /// `self >> amount`. Any `u8` is a valid amount.
+    pub fn shr(self, amount: u8) -> U256 {
+        match amount {
+            0 => self,
+            1..=127 => U256 { hi: self.hi >> amount, lo: (self.lo >> amount) | (self.hi << (128 - amount)) },
+            _ => U256 { hi: 0, lo: self.hi >> (amount - 128) },
+        }
+    }
+
+    fn bit(self, bit: u32) -> bool {
+        if bit >= 128 { (self.hi >> (bit - 128)) & 1 == 1 } else { (self.lo >> bit) & 1 == 1 }
+    }
+
+    fn limbs(self) -> [u64; 4] {
+        [self.lo as u64, (self.lo >> 64) as u64, self.hi as u64, (self.hi >> 64) as u64]
+    }
+
+    fn from_limbs64(limbs: [u64; 4]) -> U256 {
+        U256 {
+            hi: ((limbs[3] as u128) << 64) | limbs[2] as u128,
+            lo: ((limbs[1] as u128) << 64) | limbs[0] as u128,
+        }
+    }
+}
+
+impl From<u128> for U256 {
+    fn from(value: u128) -> U256 {
+        U256::from_u128(value)
+    }
+}
+
+impl BitAnd for U256 {
+    type Output = U256;
+    fn bitand(self, other: U256) -> U256 {
+        U256 { hi: self.hi & other.hi, lo: self.lo & other.lo }
+    }
+}
+
+impl BitOr for U256 {
+    type Output = U256;
+    fn bitor(self, other: U256) -> U256 {
+        U256 { hi: self.hi | other.hi, lo: self.lo | other.lo }
+    }
+}
+
+impl BitXor for U256 {
+    type Output = U256;
+    fn bitxor(self, other: U256) -> U256 {
+        U256 { hi: self.hi ^ other.hi, lo: self.lo ^ other.lo }
+    }
+}
+
+/// Decimal, as Move prints integers.
+impl fmt::Display for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(value) = self.to_u128() {
+            return write!(f, "{}", value);
+        }
+        let ten = U256::from_u128(10);
+        let mut digits = Vec::new();
+        let mut rest = *self;
+        while rest != U256::ZERO {
+            let (q, r) = rest.div_rem(ten).unwrap();
+            digits.push(b'0' + r.lo as u8);
+            rest = q;
+        }
+        digits.reverse();
+        f.write_str(std::str::from_utf8(&digits).unwrap())
+    }
+}
+
+#[cfg(kani)]
+impl kani::Arbitrary for U256 {
+    fn any() -> Self {
+        U256 { hi: kani::any(), lo: kani::any() }
+    }
+}
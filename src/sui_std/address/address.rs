@@ -0,0 +1,129 @@
+use crate::runtime::abort;
+use crate::runtime::u256::{u256, U256};
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+pub struct sui__address {}
+
+/// Move's `address`: 32 bytes, held as the `u256` they spell out.
+/// Accounts and objects share this type; object addresses are object IDs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Address {
+    value: U256,
+}
+
+/// The length of an address, in bytes
+const LENGTH: u64 = 32;
+
+// The largest integer that can be represented with 32 bytes
+const MAX: u256 = U256::MAX;
+
+/// Error from `from_bytes` when it is supplied too many or too few bytes.
+pub const EAddressParseError: u64 = 0;
+
+/// Addresses a symbolic `Address` can be under kani. Keeping the domain
+/// small lets harnesses reason about "same address" and "other address"
+/// without CBMC having to enumerate 2^256 values. None of them is reserved,
+/// harnesses that act as the system (`@0x0`) say so explicitly.
+#[cfg(kani)]
+pub const SYMBOLIC_ADDRESSES: [Address; 4] =
+    [Address::from_u128(0x1000), Address::from_u128(0x1001), Address::from_u128(0x1002), Address::from_u128(0x1003)];
+
+impl Address {
+    /// The address `@value`. The transpiler writes `@0x..` literals this way.
+    pub const fn from_u128(value: u128) -> Address {
+        Address { value: U256::from_u128(value) }
+    }
+
+    /// The address `@(hi * 2^128 + lo)`, for literals that don't fit in a `u128`.
+    pub const fn from_limbs(hi: u128, lo: u128) -> Address {
+        Address { value: U256::from_limbs(hi, lo) }
+    }
+}
+
+/// Convert `a` into a u256 by interpreting `a` as the bytes of a big-endian integer
+/// (e.g., `to_u256(0x1) == 1`)
+pub fn to_u256(a: Address) -> u256 {
+    a.value
+}
+
+/// Convert `n` into an address by encoding it as a big-endian integer (e.g., `from_u256(1) = @0x1`)
+pub fn from_u256(n: u256) -> Address {
+    Address { value: n }
+}
+
+/// Convert `bytes` into an address.
+/// Aborts with `EAddressParseError` if the length of `bytes` is not 32
+pub fn from_bytes(bytes: Vec<u8>) -> Address {
+    abort::check(bytes.len() as u64 == LENGTH, "address", "from_bytes", EAddressParseError);
+    let mut raw = [0u8; 32];
+    raw.copy_from_slice(&bytes);
+    Address { value: U256::from_be_bytes(raw) }
+}
+
+/// Convert `a` into BCS-encoded bytes.
+pub fn to_bytes(a: Address) -> Vec<u8> {
+    a.value.to_be_bytes().to_vec()
+}
+
+/// Convert `a` to a hex-encoded ASCII string
+pub fn to_ascii_string(a: Address) -> String {
+    to_string(a)
+}
+
+/// Convert `a` to a hex-encoded string
+pub fn to_string(a: Address) -> String {
+    to_bytes(a).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Convert `bytes` containing a hex-encoded address into an address.
+/// Aborts with `EAddressParseError` if the length of `bytes` is not 64 or
+/// any byte is not a hex digit.
+pub fn from_ascii_bytes(bytes: &[u8]) -> Address {
+    abort::check(bytes.len() as u64 == 2 * LENGTH, "address", "from_ascii_bytes", EAddressParseError);
+    let digits = bytes.iter().map(|b| match (*b as char).to_digit(16) {
+        Some(digit) => digit as u8,
+        None => abort::raise("address", "from_ascii_bytes", EAddressParseError),
+    });
+    let digits: Vec<u8> = digits.collect();
+    from_bytes(digits.chunks(2).map(|pair| (pair[0] << 4) | pair[1]).collect())
+}
+
+/// Length of a Sui address in bytes
+pub fn length() -> u64 {
+    LENGTH
+}
+
+/// Largest possible address
+pub fn max() -> u256 {
+    MAX
+}
+
+// === testing and verification ===
+
+/// Parse an `@0x..` or `0x..` literal, as written in Move source and harnesses.
+pub fn from_literal(literal: &str) -> Address {
+    parse(literal).expect("not an address literal")
+}
+
+/// Parse an `@0x..` or `0x..` literal, `None` if `literal` is not one.
+pub fn parse(literal: &str) -> Option<Address> {
+    let digits = literal.trim_start_matches('@').trim_start_matches("0x");
+    if digits.is_empty() || digits.len() > 64 {
+        return None;
+    }
+    let split = digits.len().saturating_sub(32);
+    let hi = if split == 0 { 0 } else { u128::from_str_radix(&digits[..split], 16).ok()? };
+    let lo = u128::from_str_radix(&digits[split..], 16).ok()?;
+    Some(Address::from_limbs(hi, lo))
+}
+
+/// One of `SYMBOLIC_ADDRESSES`.
+#[cfg(kani)]
+impl kani::Arbitrary for Address {
+    fn any() -> Self {
+        let i: usize = kani::any_where(|i: &usize| *i < SYMBOLIC_ADDRESSES.len());
+        SYMBOLIC_ADDRESSES[i]
+    }
+}
@@ -0,0 +1 @@
+pub(crate) mod address;
\ No newline at end of file
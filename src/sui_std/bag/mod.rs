@@ -0,0 +1 @@
+pub(crate) mod bag;
\ No newline at end of file
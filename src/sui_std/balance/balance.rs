@@ -1,26 +1,5 @@
-
-use std::sync::LazyLock;
-
-pub struct IdGetter {
-    current_id: std::sync::Mutex<u8>,
-}
-
-impl IdGetter {
-    pub fn new() -> Self {
-        IdGetter {
-            current_id: std::sync::Mutex::new(0),
-        }
-    }
-
-    pub fn get_new_id(&self) -> u8 {
-        let mut id = self.current_id.lock().unwrap();
-        *id += 1;
-        *id
-    }
-}
-
-// Use LazyLock to initialize ID_GETTER
-pub static ID_GETTER: LazyLock<IdGetter> = LazyLock::new(|| IdGetter::new());
+use std::marker::PhantomData;
+use crate::runtime::{abort, arith};
 
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
@@ -30,95 +9,98 @@ pub static ID_GETTER: LazyLock<IdGetter> = LazyLock::new(|| IdGetter::new());
 /// custom coins with `Supply` and `Balance`s.
 
 /// For when trying to destroy a non-zero balance.
-const ENonZero: u64 = 0;
+pub const ENonZero: u64 = 0;
 /// For when an overflow is happening on Supply operations.
-const EOverflow: u64 = 1;
+pub const EOverflow: u64 = 1;
 /// For when trying to withdraw more than there is.
-const ENotEnough: u64 = 2;
+pub const ENotEnough: u64 = 2;
 /// Sender is not @0x0 the system address.
-const ENotSystemAddress: u64 = 3;
+pub const ENotSystemAddress: u64 = 3;
 /// System operation performed for a coin other than SUI
-const ENotSUI: u64 = 4;
+pub const ENotSUI: u64 = 4;
 
 /// A Supply of T. Used for minting and burning.
 /// Wrapped into a `TreasuryCap` in the `Coin` module.
-pub struct Supply {
+pub struct Supply<T> {
     value: u64,
+    _type: PhantomData<T>,
 }
 
 /// Storable balance - an inner struct of a Coin type.
 /// Can be used to store coins which don't need the key ability.
-pub struct Balance {
+pub struct Balance<T> {
     value: u64,
+    _type: PhantomData<T>,
 }
 
-impl Balance {}
+impl<T> Balance<T> {}
 
-pub fn value(b: &Balance) -> u64 {
+/// Get the amount stored in a `Balance`.
+pub fn value<T>(b: &Balance<T>) -> u64 {
     b.value
 }
 /// Withdraw all balance. After this the remaining balance must be 0.
-pub fn withdraw_all(b: &mut Balance) -> Balance {
+pub fn withdraw_all<T>(b: &mut Balance<T>) -> Balance<T> {
     let value = b.value;
     split(b, value)
 }
 
 /// Destroy a zero `Balance`.
-pub fn destroy_zero(b: Balance) {
-    assert!(b.value == 0, "{}", ENonZero);
-    let Balance { value: _ } = b;
+pub fn destroy_zero<T>(b: Balance<T>) {
+    abort::check(b.value == 0, "balance", "destroy_zero", ENonZero);
+    let Balance { value: _, _type } = b;
 }
 
 /// Join two balances together.
-pub fn join(b: &mut Balance, balance: Balance) -> u64 {
-    let Balance { value } = balance;
-    b.value = b.value + value;
+pub fn join<T>(b: &mut Balance<T>, balance: Balance<T>) -> u64 {
+    let Balance { value, _type } = balance;
+    b.value = arith::add(b.value, value);
     b.value
 }
 
 /// Split a `Balance` and take a sub balance from it.
-pub fn split(b: &mut Balance, value: u64) -> Balance {
-    assert!(b.value >= value, "{}", ENotEnough);
-    b.value = b.value - value;
-    Balance { value }
+pub fn split<T>(b: &mut Balance<T>, value: u64) -> Balance<T> {
+    abort::check(b.value >= value, "balance", "split", ENotEnough);
+    b.value = arith::sub(b.value, value);
+    Balance { value, _type: PhantomData }
 }
 
 
 /// Get the `Supply` value.
-pub fn supply_value(supply: &Supply) -> u64 {
+pub fn supply_value<T>(supply: &Supply<T>) -> u64 {
     supply.value
 }
 
 /// Create a new supply for type T.
-pub fn create_supply<T>(_: T) -> Supply {
-    Supply { value: 0 }
+pub fn create_supply<T>(_: T) -> Supply<T> {
+    Supply { value: 0, _type: PhantomData }
 }
 
-impl Supply{
+impl<T> Supply<T> {
     /// Increase supply by `value` and create a new `Balance` with this value.
-    pub fn increase_supply(self: &mut Supply, value: u64) -> Balance {
-        assert!(value < (18446744073709551615u64 - self.value), "{}", EOverflow);
-        self.value = self.value + value;
-        Balance { value }
+    pub fn increase_supply(self: &mut Supply<T>, value: u64) -> Balance<T> {
+        abort::check(value < arith::sub(18446744073709551615u64, self.value), "balance", "increase_supply", EOverflow);
+        self.value = arith::add(self.value, value);
+        Balance { value, _type: PhantomData }
     }
 
     /// Burn a Balance and decrease Supply.
-    pub fn decrease_supply(self: &mut Supply, balance: Balance) -> u64 {
-        let Balance { value } = balance;
-        assert!(self.value >= value, "{}", EOverflow);
-        self.value = self.value - value;
+    pub fn decrease_supply(self: &mut Supply<T>, balance: Balance<T>) -> u64 {
+        let Balance { value, _type } = balance;
+        abort::check(self.value >= value, "balance", "decrease_supply", EOverflow);
+        self.value = arith::sub(self.value, value);
         value
     }
 
     /// Destroy a `Supply` preventing any further minting and burning.
-    pub fn destroy_supply(self: Supply) -> u64 {
-        let Supply { value } = self;
+    pub fn destroy_supply(self: Supply<T>) -> u64 {
+        let Supply { value, _type } = self;
         value
     }
 }
 
 
 /// Create a zero `Balance` for type `T`.
-pub fn zero() -> Balance {
-    Balance { value: 0 }
+pub fn zero<T>() -> Balance<T> {
+    Balance { value: 0, _type: PhantomData }
 }
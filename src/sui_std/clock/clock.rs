@@ -0,0 +1,102 @@
+use crate::runtime::{abort, arith};
+use crate::sui_std::address::address::Address;
+use crate::sui_std::object::object::{self, KeyObject, UID};
+use crate::sui_std::transfer::transfer;
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// APIs for accessing time from move calls, via the `Clock`: a unique
+/// shared object that is created at 0x6 during genesis.
+pub struct sui__clock {}
+
+/// Sender is not @0x0 the system address.
+pub const ENotSystemAddress: u64 = 0;
+
+/// Singleton shared object that exposes time to Move calls.  This
+/// object is found at address 0x6, and can only be read (accessed
+/// via an immutable reference) by entry functions.
+///
+/// Entry Functions that attempt to accept `Clock` by value will fail
+/// to verify, and by mutable reference will fail to sign.
+pub struct Clock {
+    id: UID,
+    /// The clock's timestamp, which is set automatically by a
+    /// system transaction every time consensus commits a
+    /// schedule, or by `sui::clock::increment_for_testing` during
+    /// testing.
+    timestamp_ms: u64,
+}
+
+impl KeyObject for Clock {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+impl Clock {
+    /// The `clock`'s current timestamp as a running total of
+    /// milliseconds since an arbitrary point in the past.
+    pub fn timestamp_ms(self: &Clock) -> u64 {
+        self.timestamp_ms
+    }
+}
+
+/// The `clock`'s current timestamp as a running total of
+/// milliseconds since an arbitrary point in the past.
+pub fn timestamp_ms(clock: &Clock) -> u64 {
+    clock.timestamp_ms
+}
+
+/// Sets the `clock`'s timestamp at the start of every consensus commit.
+/// Only the system address can do this, and time never goes backwards.
+pub fn consensus_commit_prologue(clock: &mut Clock, timestamp_ms: u64, ctx: &TxContext) {
+    // Validator will make a special system call with sender set as 0x0.
+    abort::check(
+        tx_context::sender(ctx) == Address::from_u128(0x0),
+        "clock",
+        "consensus_commit_prologue",
+        ENotSystemAddress,
+    );
+    clock.timestamp_ms = timestamp_ms
+}
+
+// === testing and verification ===
+
+/// Expose the functionality of `create()` (usually only done during genesis) for tests
+/// that want to create a Clock.
+pub fn create_for_testing(ctx: &mut TxContext) -> Clock {
+    Clock {
+        id: object::new(ctx),
+        timestamp_ms: 0,
+    }
+}
+
+/// For transactional tests (if a Clock is used as a shared object).
+pub fn share_for_testing(clock: Clock) {
+    transfer::share_object(clock)
+}
+
+/// For transactional tests (if a Clock is used as a shared object).
+pub fn increment_for_testing(clock: &mut Clock, tick: u64) {
+    clock.timestamp_ms = arith::add(clock.timestamp_ms, tick);
+}
+
+/// Move's `assert!` here carries no error code, 0 stands in for it.
+pub fn set_for_testing(clock: &mut Clock, timestamp_ms: u64) {
+    abort::check(timestamp_ms >= clock.timestamp_ms, "clock", "set_for_testing", 0);
+    clock.timestamp_ms = timestamp_ms;
+}
+
+pub fn destroy_clock_for_testing(clock: Clock) {
+    let Clock { id, timestamp_ms: _ } = clock;
+    object::delete(id);
+}
+
+/// Let an unknown amount of time pass, as between two transactions: the
+/// timestamp moves to any value that is not in the past.
+#[cfg(kani)]
+pub fn advance(clock: &mut Clock) {
+    let timestamp_ms: u64 = kani::any();
+    kani::assume(timestamp_ms >= clock.timestamp_ms);
+    clock.timestamp_ms = timestamp_ms;
+}
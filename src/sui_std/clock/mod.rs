@@ -0,0 +1 @@
+pub(crate) mod clock;
\ No newline at end of file
@@ -1,28 +1,14 @@
+use std::any::TypeId;
+use std::marker::PhantomData;
+use crate::runtime::{abort, arith};
 use crate::sui_std::balance::balance;
+use crate::sui_std::deny_list::deny_list::{self, DenyList};
 use balance::{Balance, Supply};
+use crate::sui_std::address::address::Address;
+use crate::sui_std::pay::pay;
 use crate::sui_std::transfer::transfer;
-use std::sync::LazyLock;
-
-pub struct IdGetter {
-    current_id: std::sync::Mutex<u8>,
-}
-
-impl IdGetter {
-    pub fn new() -> Self {
-        IdGetter {
-            current_id: std::sync::Mutex::new(0),
-        }
-    }
-
-    pub fn get_new_id(&self) -> u8 {
-        let mut id = self.current_id.lock().unwrap();
-        *id += 1;
-        *id
-    }
-}
-
-// Use LazyLock to initialize ID_GETTER
-pub static ID_GETTER: LazyLock<IdGetter> = LazyLock::new(|| IdGetter::new());
+use crate::sui_std::object::object::{self, KeyObject, ID, UID};
+use crate::sui_std::tx_context::tx_context::TxContext;
 
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
@@ -32,35 +18,27 @@ pub static ID_GETTER: LazyLock<IdGetter> = LazyLock::new(|| IdGetter::new());
 /// `Balance` type.
 pub struct sui__coin {}
 
-// Allows calling `.split_vec(amounts, ctx)` on `coin`
-
-// Allows calling `.join_vec(coins)` on `coin`
-
-// Allows calling `.split_and_transfer(amount, recipient, ctx)` on `coin`
-
-// Allows calling `.divide_and_keep(n, ctx)` on `coin`
-
 /// A type passed to create_supply is not a one-time witness.
-const EBadWitness: u64 = 0;
+pub const EBadWitness: u64 = 0;
 /// Invalid arguments are passed to a fnction.
-const EInvalidArg: u64 = 1;
+pub const EInvalidArg: u64 = 1;
 /// Trying to split a coin more times than its balance allows.
-const ENotEnough: u64 = 2;
+pub const ENotEnough: u64 = 2;
 // #[error]
 // const EGlobalPauseNotAllowed: Vec<u8> =
 //    b"Kill switch was not allowed at the creation of the DenyCapV2";
-const EGlobalPauseNotAllowed: u64 = 3;
+pub const EGlobalPauseNotAllowed: u64 = 3;
 
 /// A coin of type `T` worth `value`. Transferable and storable
-pub struct Coin {
-    id: u8,
-    balance: Balance,
+pub struct Coin<T> {
+    id: UID,
+    balance: Balance<T>,
 }
 
 /// Each Coin type T created through `create_currency` fnction will have a
 /// unique instance of CoinMetadata that stores the metadata for this coin type.
-pub struct CoinMetadata {
-    id: u8,
+pub struct CoinMetadata<T> {
+    id: UID,
     /// Number of decimal places the coin uses.
     /// A coin with `value ` N and `decimals` D should be shown as N / 10^D
     /// E.g., a coin with `value` 7002 and decimals 3 should be displayed as 7.002
@@ -74,39 +52,63 @@ pub struct CoinMetadata {
     description: String,
     /// URL for the token logo
     icon_url: Option<String>,
+    _type: PhantomData<T>,
 }
 
 /// Similar to CoinMetadata, but created only for regulated coins that 
 /// This object is always immutable.
-pub struct RegulatedCoinMetadata {
-    id: u8,
-    /// The u8 of the coin's CoinMetadata object.
-    coin_metadata_object: u8,
-    /// The u8 of the coin's DenyCap object.
-    deny_cap_object: u8,
+pub struct RegulatedCoinMetadata<T> {
+    id: UID,
+    /// The ID of the coin's CoinMetadata object.
+    coin_metadata_object: ID,
+    /// The ID of the coin's DenyCap object.
+    deny_cap_object: ID,
+    _type: PhantomData<T>,
 }
 
 /// Capability allowing the bearer to mint and burn
 /// coins of type `T`. Transferable
-pub struct TreasuryCap {
-    id: u8,
-    total_supply: Supply,
+pub struct TreasuryCap<T> {
+    id: UID,
+    total_supply: Supply<T>,
 }
 
-/// Capability allowing the bearer to deny Stringes from using the currency's coins--
-/// immediately preventing those Stringes from interacting with the coin as an input to a
+/// Capability allowing the bearer to deny addresses from using the currency's coins--
+/// immediately preventing those addresses from interacting with the coin as an input to a
 /// transaction and at the start of the next preventing them from receiving the coin.
 /// If `allow_global_pause` is true, the bearer can enable a global pa
-/// all Stringes were added to the deny list.
-pub struct DenyCapV2 {
-    id: u8,
+/// all addresses were added to the deny list.
+pub struct DenyCapV2<T> {
+    id: UID,
     allow_global_pause: bool,
+    _type: PhantomData<T>,
+}
+
+impl<T: 'static> KeyObject for Coin<T> {
+    fn uid(&self) -> &UID { &self.id }
+    fn coin_type(&self) -> Option<TypeId> { Some(TypeId::of::<T>()) }
+}
+
+impl<T> KeyObject for CoinMetadata<T> {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+impl<T> KeyObject for RegulatedCoinMetadata<T> {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+impl<T> KeyObject for TreasuryCap<T> {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+impl<T> KeyObject for DenyCapV2<T> {
+    fn uid(&self) -> &UID { &self.id }
 }
 
 // === Supply <-> TreasuryCap morphing and accessors  ===
 
 /// Return the total number of `T`'s in circulation.
-pub fn total_supply(cap: &TreasuryCap) -> u64 {
+pub fn total_supply<T>(cap: &TreasuryCap<T>) -> u64 {
     balance::supply_value(&cap.total_supply)
 }
 
@@ -114,41 +116,97 @@ pub fn total_supply(cap: &TreasuryCap) -> u64 {
 ///
 /// Operation is irreversible. Supply cannot be converted into a `TreasuryCap` due
 /// to different security guarantees (TreasuryCap can be created only once for a type)
-pub fn treasury_into_supply(treasury: TreasuryCap) -> Supply {
+pub fn treasury_into_supply<T>(treasury: TreasuryCap<T>) -> Supply<T> {
     let TreasuryCap { id, total_supply } = treasury;
+    object::delete(id);
     total_supply
 }
 
 /// Get immutable reference to the treasury's `Supply`.
-pub fn supply_immut(treasury: &TreasuryCap) -> &Supply {
+pub fn supply_immut<T>(treasury: &TreasuryCap<T>) -> &Supply<T> {
     &treasury.total_supply
 }
 
 /// Get mutable reference to the treasury's `Supply`.
-pub fn supply_mut(treasury: &mut TreasuryCap) -> &mut Supply {
+pub fn supply_mut<T>(treasury: &mut TreasuryCap<T>) -> &mut Supply<T> {
     &mut treasury.total_supply
 }
 
 // === Balance <-> Coin accessors and type morphing ===
 
-impl Coin{}
+/// Method-style calls, as Move allows on `Coin` for the functions of this module
+/// and the `use fun` aliases into `sui::pay`.
+impl<T: 'static> Coin<T> {
+    pub fn value(self: &Coin<T>) -> u64 {
+        value(self)
+    }
+
+    pub fn balance(self: &Coin<T>) -> &Balance<T> {
+        balance(self)
+    }
+
+    pub fn balance_mut(self: &mut Coin<T>) -> &mut Balance<T> {
+        balance_mut(self)
+    }
+
+    pub fn into_balance(self: Coin<T>) -> Balance<T> {
+        into_balance(self)
+    }
+
+    pub fn split(self: &mut Coin<T>, split_amount: u64, ctx: &mut TxContext) -> Coin<T> {
+        split(self, split_amount, ctx)
+    }
+
+    pub fn divide_into_n(self: &mut Coin<T>, n: u64, ctx: &mut TxContext) -> Vec<Coin<T>> {
+        divide_into_n(self, n, ctx)
+    }
+
+    pub fn join(self: &mut Coin<T>, c: Coin<T>) {
+        join(self, c)
+    }
+
+    pub fn destroy_zero(self: Coin<T>) {
+        destroy_zero(self)
+    }
+
+    // Allows calling `.split_vec(amounts, ctx)` on `coin`
+    pub fn split_vec(self: &mut Coin<T>, split_amounts: Vec<u64>, ctx: &mut TxContext) {
+        pay::split_vec(self, split_amounts, ctx)
+    }
+
+    // Allows calling `.join_vec(coins)` on `coin`
+    pub fn join_vec(self: &mut Coin<T>, coins: Vec<Coin<T>>) {
+        pay::join_vec(self, coins)
+    }
+
+    // Allows calling `.split_and_transfer(amount, recipient, ctx)` on `coin`
+    pub fn split_and_transfer(self: &mut Coin<T>, amount: u64, recipient: Address, ctx: &mut TxContext) {
+        pay::split_and_transfer(self, amount, recipient, ctx)
+    }
+
+    // Allows calling `.divide_and_keep(n, ctx)` on `coin`
+    pub fn divide_and_keep(self: &mut Coin<T>, n: u64, ctx: &mut TxContext) {
+        pay::divide_and_keep(self, n, ctx)
+    }
+}
+
 /// Public getter for the coin's value
-pub fn value(c: &Coin) -> u64 {
+pub fn value<T>(c: &Coin<T>) -> u64 {
     balance::value(&c.balance)
 }
 
 /// Get immutable reference to the balance of a coin.
-pub fn balance(c: &Coin) -> &Balance {
+pub fn balance<T>(c: &Coin<T>) -> &Balance<T> {
     &c.balance
 }
 
 /// Get a mutable reference to the balance of a coin.
-pub fn balance_mut(c: &mut Coin) -> &mut Balance {
+pub fn balance_mut<T>(c: &mut Coin<T>) -> &mut Balance<T> {
     &mut c.balance
 }
 
 /// Put a `Coin` to the `Balance`.
-pub fn put(balance: &mut Balance, coin: Coin) {
+pub fn put<T>(balance: &mut Balance<T>, coin: Coin<T>) {
     balance::join(balance, into_balance(coin));
 }
 
@@ -156,62 +214,65 @@ pub fn put(balance: &mut Balance, coin: Coin) {
 
 /// Split coin `self` to two coins, one with balance `split_amount`,
 /// and the remaining balance is left is `self`.
-pub fn split(c: &mut Coin, split_amount: u64, ) -> Coin {
-    take(&mut c.balance, split_amount)
+pub fn split<T>(c: &mut Coin<T>, split_amount: u64, ctx: &mut TxContext) -> Coin<T> {
+    take(&mut c.balance, split_amount, ctx)
 }
 
 /// Split coin `self` into `n - 1` coins with equal balances. The remainder is left in
 /// `self`. Return newly created coins.
-pub fn divide_into_n(c: &mut Coin, n: u64, ) -> Vec<Coin> {
-    assert!(n > 0, "{}", EInvalidArg);
-    assert!(n <= value(c), "{}", ENotEnough);
+pub fn divide_into_n<T>(c: &mut Coin<T>, n: u64, ctx: &mut TxContext) -> Vec<Coin<T>> {
+    abort::check(n > 0, "coin", "divide_into_n", EInvalidArg);
+    abort::check(n <= value(c), "coin", "divide_into_n", ENotEnough);
 
     let mut vec = Vec::new();
     let mut i = 0;
-    let split_amount = value(c) / n;
-    while i < n - 1 {
-        vec.push(split(c, split_amount));
-        i = i + 1;
+    let split_amount = arith::div(value(c), n);
+    while i < arith::sub(n, 1) {
+        vec.push(split(c, split_amount, ctx));
+        i = arith::add(i, 1);
     };
     vec
 }
 
 /// Make any Coin with a zero value. Useful for placeholding
 /// bids/payments or preemptively making empty balances.
-pub fn zero() -> Coin {
-    Coin { id: ID_GETTER.get_new_id(), balance: balance::zero() }
+pub fn zero<T>(ctx: &mut TxContext) -> Coin<T> {
+    Coin { id: object::new(ctx), balance: balance::zero() }
 }
 
 /// Destroy a coin with value zero
-pub fn destroy_zero(c: Coin) {
+pub fn destroy_zero<T>(c: Coin<T>) {
     let Coin { id, balance } = c;
+    object::delete(id);
     balance::destroy_zero(balance)
 }
 
 /// Consume the coin `c` and add its value to `self`.
 /// Aborts if `c.value + self.value > U64_MAX`
-pub fn join(this: &mut Coin, c: Coin) {
+pub fn join<T>(this: &mut Coin<T>, c: Coin<T>) {
     let Coin { id, balance } = c;
+    object::delete(id);
     balance::join(&mut this.balance, balance);
 }
 
 /// Take a `Coin` worth of `value` from `Balance`.
 /// Aborts if `value > balance.value`
-pub fn take(balance: &mut Balance, value: u64, ) -> Coin {
+pub fn take<T>(balance: &mut Balance<T>, value: u64, ctx: &mut TxContext) -> Coin<T> {
     Coin {
-        id: ID_GETTER.get_new_id(),
+        id: object::new(ctx),
         balance: balance::split(balance, value),
     }
 }
 
 /// Wrap a balance into a Coin to make it transferable.
-pub fn from_balance(balance: Balance, ) -> Coin {
-    Coin { id: ID_GETTER.get_new_id(), balance }
+pub fn from_balance<T>(balance: Balance<T>, ctx: &mut TxContext) -> Coin<T> {
+    Coin { id: object::new(ctx), balance }
 }
 
 /// Destruct a Coin wrapper and keep the balance.
-pub fn into_balance(c: Coin) -> Balance {
+pub fn into_balance<T>(c: Coin<T>) -> Balance<T> {
     let Coin { id, balance } = c;
+    object::delete(id);
     balance
 }
 
@@ -227,30 +288,72 @@ pub fn create_currency<T>(
     name: Vec<u8>,
     description: Vec<u8>,
     icon_url: Option<String>,
-    
-) -> (TreasuryCap, CoinMetadata) {
+    ctx: &mut TxContext,
+) -> (TreasuryCap<T>, CoinMetadata<T>) {
     // Make sure there's only one instance of the type T
     (
         TreasuryCap {
-            id: ID_GETTER.get_new_id(),
+            id: object::new(ctx),
             total_supply: balance::create_supply(witness),
         },
         CoinMetadata {
-            id: ID_GETTER.get_new_id(),
+            id: object::new(ctx),
             decimals,
             name: format!("{:?}", name),
             symbol:format!("{:?}", symbol),
             description: format!("{:?}", description),
             icon_url,
+            _type: PhantomData,
         },
     )
 }
 
+/// This creates a new currency, via `create_currency`, but with an extra capability that
+/// allows for specific addresses to have their coins frozen. When an address is added to the
+/// deny list, it is immediately unable to interact with the currency's coin as input objects.
+/// Additionally at the start of the next epoch, they will be unable to receive the currency's
+/// coin.
+/// The `allow_global_pause` flag enables an additional API that will cause all addresses to
+/// be denied. Note however, that this doesn't affect per-address entries of the deny list and
+/// will not change the result of the "contains" APIs.
+pub fn create_regulated_currency_v2<T: 'static>(
+    witness: T,
+    decimals: u8,
+    symbol: Vec<u8>,
+    name: Vec<u8>,
+    description: Vec<u8>,
+    icon_url: Option<String>,
+    allow_global_pause: bool,
+    ctx: &mut TxContext,
+) -> (TreasuryCap<T>, DenyCapV2<T>, CoinMetadata<T>) {
+    let (treasury_cap, metadata) = create_currency(
+        witness,
+        decimals,
+        symbol,
+        name,
+        description,
+        icon_url,
+        ctx,
+    );
+    let deny_cap = DenyCapV2 {
+        id: object::new(ctx),
+        allow_global_pause,
+        _type: PhantomData,
+    };
+    transfer::freeze_object(RegulatedCoinMetadata::<T> {
+        id: object::new(ctx),
+        coin_metadata_object: object::id(&metadata),
+        deny_cap_object: object::id(&deny_cap),
+        _type: PhantomData,
+    });
+    (treasury_cap, deny_cap, metadata)
+}
+
 /// Create a coin worth `value` and increase the total supply
 /// in `cap` accordingly.
-pub fn mint(cap: &mut TreasuryCap, value: u64, ) -> Coin {
+pub fn mint<T>(cap: &mut TreasuryCap<T>, value: u64, ctx: &mut TxContext) -> Coin<T> {
     Coin {
-        id: ID_GETTER.get_new_id(),
+        id: object::new(ctx),
         balance: cap.total_supply.increase_supply(value),
     }
 }
@@ -258,62 +361,143 @@ pub fn mint(cap: &mut TreasuryCap, value: u64, ) -> Coin {
 /// Mint some amount of T as a `Balance` and increase the total
 /// supply in `cap` accordingly.
 /// Aborts if `value` + `cap.total_supply` >= U64_MAX
-pub fn mint_balance(cap: &mut TreasuryCap, value: u64) -> Balance {
+pub fn mint_balance<T>(cap: &mut TreasuryCap<T>, value: u64) -> Balance<T> {
     cap.total_supply.increase_supply(value)
 }
 
 /// Destroy the coin `c` and decrease the total supply in `cap`
 /// accordingly.
-pub fn burn(cap: &mut TreasuryCap, c: Coin) -> u64 {
+pub fn burn<T>(cap: &mut TreasuryCap<T>, c: Coin<T>) -> u64 {
     let Coin { id, balance } = c;
+    object::delete(id);
     cap.total_supply.decrease_supply(balance)
 }
 
 // === Entrypoints ===
 
 /// Mint `amount` of `Coin` and send it to `recipient`. Invokes `mint()`.
-pub fn mint_and_transfer(
-    c: &mut TreasuryCap,
+pub fn mint_and_transfer<T: 'static>(
+    c: &mut TreasuryCap<T>,
     amount: u64,
-    recipient: String,
-    
+    recipient: Address,
+    ctx: &mut TxContext,
 ) {
-    transfer::pub_transfer(mint(c, amount), recipient)
+    transfer::pub_transfer(mint(c, amount, ctx), recipient)
+}
+
+// === Regulated coins ===
+
+/// Adds the given address to the deny list, preventing it from interacting with the specified
+/// coin type as an input to a transaction. Additionally at the start of the next epoch, the
+/// address will be unable to receive objects of this coin type.
+pub fn deny_list_v2_add<T: 'static>(
+    deny_list: &mut DenyList,
+    _deny_cap: &mut DenyCapV2<T>,
+    addr: Address,
+    ctx: &mut TxContext,
+) {
+    deny_list::v2_add(deny_list, deny_list::COIN_INDEX, TypeId::of::<T>(), addr, ctx)
+}
+
+/// Removes an address from the deny list. Similar to `deny_list_v2_add`, the effect for input
+/// objects will be immediate, but the effect for receiving objects will be delayed until the
+/// next epoch.
+pub fn deny_list_v2_remove<T: 'static>(
+    deny_list: &mut DenyList,
+    _deny_cap: &mut DenyCapV2<T>,
+    addr: Address,
+    ctx: &mut TxContext,
+) {
+    deny_list::v2_remove(deny_list, deny_list::COIN_INDEX, TypeId::of::<T>(), addr, ctx)
+}
+
+/// Check if the deny list contains the given address for the current epoch. Denied addresses
+/// in the current epoch will be unable to receive objects of this coin type.
+pub fn deny_list_v2_contains_current_epoch<T: 'static>(
+    deny_list: &DenyList,
+    addr: Address,
+    ctx: &TxContext,
+) -> bool {
+    deny_list::v2_contains_current_epoch(deny_list, deny_list::COIN_INDEX, TypeId::of::<T>(), addr, ctx)
+}
+
+/// Check if the deny list contains the given address for the next epoch. Denied addresses in
+/// the next epoch will immediately be unable to use objects of this coin type as inputs. At the
+/// start of the next epoch, the address will be unable to receive objects of this coin type.
+pub fn deny_list_v2_contains_next_epoch<T: 'static>(deny_list: &DenyList, addr: Address) -> bool {
+    deny_list::v2_contains_next_epoch(deny_list, deny_list::COIN_INDEX, TypeId::of::<T>(), addr)
+}
+
+/// Enable the global pause for the given coin type. This will immediately prevent all addresses
+/// from using objects of this coin type as inputs. At the start of the next epoch, all addresses
+/// will be unable to receive objects of this coin type.
+pub fn deny_list_v2_enable_global_pause<T: 'static>(
+    deny_list: &mut DenyList,
+    deny_cap: &mut DenyCapV2<T>,
+    ctx: &mut TxContext,
+) {
+    abort::check(deny_cap.allow_global_pause, "coin", "deny_list_v2_enable_global_pause", EGlobalPauseNotAllowed);
+    deny_list::v2_enable_global_pause(deny_list, deny_list::COIN_INDEX, TypeId::of::<T>(), ctx)
+}
+
+/// Disable the global pause for the given coin type. This will immediately allow all addresses
+/// to resume using objects of this coin type as inputs. However, receiving objects of this coin
+/// type will still be paused until the start of the next epoch.
+pub fn deny_list_v2_disable_global_pause<T: 'static>(
+    deny_list: &mut DenyList,
+    deny_cap: &mut DenyCapV2<T>,
+    ctx: &mut TxContext,
+) {
+    abort::check(deny_cap.allow_global_pause, "coin", "deny_list_v2_disable_global_pause", EGlobalPauseNotAllowed);
+    deny_list::v2_disable_global_pause(deny_list, deny_list::COIN_INDEX, TypeId::of::<T>(), ctx)
+}
+
+/// Check if the global pause is enabled for the given coin type in the current epoch.
+pub fn deny_list_v2_is_global_pause_enabled_current_epoch<T: 'static>(
+    deny_list: &DenyList,
+    ctx: &TxContext,
+) -> bool {
+    deny_list::v2_is_global_pause_enabled_current_epoch(deny_list, deny_list::COIN_INDEX, TypeId::of::<T>(), ctx)
+}
+
+/// Check if the global pause is enabled for the given coin type in the next epoch.
+pub fn deny_list_v2_is_global_pause_enabled_next_epoch<T: 'static>(deny_list: &DenyList) -> bool {
+    deny_list::v2_is_global_pause_enabled_next_epoch(deny_list, deny_list::COIN_INDEX, TypeId::of::<T>())
 }
 
 // === Update coin metadata ===
 
 /// Update name of the coin in `CoinMetadata`
-pub fn update_name(
-    _treasury: &TreasuryCap,
-    metadata: &mut CoinMetadata,
+pub fn update_name<T>(
+    _treasury: &TreasuryCap<T>,
+    metadata: &mut CoinMetadata<T>,
     name: String,
 ) {
     metadata.name = name;
 }
 
 /// Update the symbol of the coin in `CoinMetadata`
-pub fn update_symbol(
-    _treasury: &TreasuryCap,
-    metadata: &mut CoinMetadata,
+pub fn update_symbol<T>(
+    _treasury: &TreasuryCap<T>,
+    metadata: &mut CoinMetadata<T>,
     symbol: String,
 ) {
     metadata.symbol = symbol;
 }
 
 /// Update the description of the coin in `CoinMetadata`
-pub fn update_description(
-    _treasury: &TreasuryCap,
-    metadata: &mut CoinMetadata,
+pub fn update_description<T>(
+    _treasury: &TreasuryCap<T>,
+    metadata: &mut CoinMetadata<T>,
     description: String,
 ) {
     metadata.description = description;
 }
 
 /// Update the url of the coin in `CoinMetadata`
-pub fn update_icon_url(
-    _treasury: &TreasuryCap,
-    metadata: &mut CoinMetadata,
+pub fn update_icon_url<T>(
+    _treasury: &TreasuryCap<T>,
+    metadata: &mut CoinMetadata<T>,
     url: String,
 ) {
     metadata.icon_url = Option::Some(url);
@@ -321,22 +505,22 @@ pub fn update_icon_url(
 
 // === Get coin metadata fields for on-chain consumption ===
 
-pub fn get_decimals(metadata: &CoinMetadata) -> u8 {
+pub fn get_decimals<T>(metadata: &CoinMetadata<T>) -> u8 {
     metadata.decimals
 }
 
-pub fn get_name(metadata: &CoinMetadata) -> String {
+pub fn get_name<T>(metadata: &CoinMetadata<T>) -> String {
     metadata.name.clone()
 }
 
-pub fn get_symbol(metadata: &CoinMetadata) -> String {
+pub fn get_symbol<T>(metadata: &CoinMetadata<T>) -> String {
     metadata.symbol.clone()
 }
 
-pub fn get_description(metadata: &CoinMetadata) -> String {
+pub fn get_description<T>(metadata: &CoinMetadata<T>) -> String {
     metadata.description.clone()
 }
 
-pub fn get_icon_url(metadata: &CoinMetadata) -> Option<String> {
+pub fn get_icon_url<T>(metadata: &CoinMetadata<T>) -> Option<String> {
     metadata.icon_url.clone()
 }
\ No newline at end of file
@@ -0,0 +1,139 @@
+use std::any::TypeId;
+use crate::runtime::abort;
+use crate::runtime::deny::{self, Key};
+use crate::sui_std::address::address::Address;
+use crate::sui_std::object::object::{self, KeyObject, UID};
+use crate::sui_std::transfer::transfer;
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Defines the `DenyList` type. The `DenyList` shared object is used to restrict access to
+/// instances of certain core types from being used as inputs by specified addresses in the deny
+/// list.
+pub struct sui__deny_list {}
+
+/// Trying to create a deny list object when not called by the system address.
+pub const ENotSystemAddress: u64 = 0;
+/// The specified address to be removed is not already in the deny list.
+pub const ENotDenied: u64 = 1;
+/// The specified address cannot be added to the deny list.
+pub const EInvalidAddress: u64 = 2;
+
+/// The index into the deny list vector for the `sui::coin::Coin` type.
+pub const COIN_INDEX: u64 = deny::COIN_INDEX;
+
+/// These addresses are reserved and cannot be added to the deny list.
+/// The addresses listed are well known package and object addresses. So it would be
+/// meaningless to add them to the deny list.
+const RESERVED: [u128; 18] =
+    [0x0, 0x1, 0x2, 0x3, 0x4, 0x5, 0x6, 0x7, 0x8, 0x9, 0xA, 0xB, 0xC, 0xD, 0xE, 0xF, 0x403, 0xdee9];
+
+/// A shared object that stores the addresses that are blocked for a given core type.
+///
+/// The lists themselves live in `runtime::deny`, where the ledger checks them
+/// on every coin taken or transferred, as validators do on chain.
+pub struct DenyList {
+    id: UID,
+}
+
+impl KeyObject for DenyList {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+// === V2 ===
+
+/// Adds `addr` to the list `per_type_index` of `per_type_key`, from the next epoch on
+/// for receiving and immediately for use as transaction input.
+pub fn v2_add(
+    _deny_list: &mut DenyList,
+    per_type_index: u64,
+    per_type_key: TypeId,
+    addr: Address,
+    _ctx: &mut TxContext,
+) {
+    abort::check(!RESERVED.iter().any(|a| Address::from_u128(*a) == addr), "deny_list", "v2_add", EInvalidAddress);
+    deny::set_for_next_epoch(per_type_index, per_type_key, Key::Address(addr), true)
+}
+
+/// Removes `addr` from the list, with the same timing as `v2_add`.
+pub fn v2_remove(
+    _deny_list: &mut DenyList,
+    per_type_index: u64,
+    per_type_key: TypeId,
+    addr: Address,
+    _ctx: &mut TxContext,
+) {
+    deny::set_for_next_epoch(per_type_index, per_type_key, Key::Address(addr), false)
+}
+
+/// Whether `addr` is denied in the current epoch.
+pub fn v2_contains_current_epoch(
+    _deny_list: &DenyList,
+    per_type_index: u64,
+    per_type_key: TypeId,
+    addr: Address,
+    _ctx: &TxContext,
+) -> bool {
+    deny::read_current_epoch(per_type_index, per_type_key, Key::Address(addr))
+}
+
+/// Whether `addr` is denied from the next epoch on.
+pub fn v2_contains_next_epoch(_deny_list: &DenyList, per_type_index: u64, per_type_key: TypeId, addr: Address) -> bool {
+    deny::read_next_epoch(per_type_index, per_type_key, Key::Address(addr))
+}
+
+/// Denies every address, with the same timing as `v2_add`.
+pub fn v2_enable_global_pause(
+    _deny_list: &mut DenyList,
+    per_type_index: u64,
+    per_type_key: TypeId,
+    _ctx: &mut TxContext,
+) {
+    deny::set_for_next_epoch(per_type_index, per_type_key, Key::GlobalPause, true)
+}
+
+/// Lifts the global pause, with the same timing as `v2_add`.
+pub fn v2_disable_global_pause(
+    _deny_list: &mut DenyList,
+    per_type_index: u64,
+    per_type_key: TypeId,
+    _ctx: &mut TxContext,
+) {
+    deny::set_for_next_epoch(per_type_index, per_type_key, Key::GlobalPause, false)
+}
+
+/// Whether the global pause is on in the current epoch.
+pub fn v2_is_global_pause_enabled_current_epoch(
+    _deny_list: &DenyList,
+    per_type_index: u64,
+    per_type_key: TypeId,
+    _ctx: &TxContext,
+) -> bool {
+    deny::read_current_epoch(per_type_index, per_type_key, Key::GlobalPause)
+}
+
+/// Whether the global pause is on from the next epoch on.
+pub fn v2_is_global_pause_enabled_next_epoch(_deny_list: &DenyList, per_type_index: u64, per_type_key: TypeId) -> bool {
+    deny::read_next_epoch(per_type_index, per_type_key, Key::GlobalPause)
+}
+
+/// Creation of the deny list object is restricted to the system address
+/// via a system transaction.
+pub fn create(ctx: &mut TxContext) {
+    abort::check(tx_context::sender(ctx) == Address::from_u128(0x0), "deny_list", "create", ENotSystemAddress);
+    transfer::share_object(DenyList { id: object::new(ctx) })
+}
+
+// === testing and verification ===
+
+pub fn create_for_testing(ctx: &mut TxContext) {
+    transfer::share_object(new_for_testing(ctx))
+}
+
+/// Creates and returns a new DenyList object for testing purposes. It
+/// doesn't matter which object ID the list has in this kind of test.
+pub fn new_for_testing(ctx: &mut TxContext) -> DenyList {
+    DenyList { id: object::new(ctx) }
+}
@@ -0,0 +1 @@
+pub(crate) mod deny_list;
\ No newline at end of file
@@ -0,0 +1,98 @@
+use std::any::Any;
+use crate::runtime::abort;
+use crate::sui_std::object::object::UID;
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// In addition to the fields declared in its type definition, a Sui object can have dynamic fields
+/// that can be added after the object has been constructed. Unlike ordinary field names
+/// (which are always statically declared identifiers) a dynamic field name can be any value with
+/// the `copy`, `drop`, and `store` abilities, e.g. an integer, a boolean, or a string.
+/// This gives Sui programmers the flexibility to extend objects on-the-fly, and it also serves as a
+/// building block for core collection types
+pub struct sui__dynamic_field {}
+
+/// The object already has a dynamic field with this name (with the value and type specified)
+pub const EFieldAlreadyExists: u64 = 0;
+/// Cannot load dynamic field.
+/// The object does not have a dynamic field with this name (with the value and type specified)
+pub const EFieldDoesNotExist: u64 = 1;
+/// The object has a field with that name, but the value type does not match
+pub const EFieldTypeMismatch: u64 = 2;
+
+/// One dynamic field. Fields are told apart by name value and name type, a
+/// name of another type never matches even if it is spelled the same.
+pub(crate) struct Field {
+    name: Box<dyn Any>,
+    value: Box<dyn Any>,
+}
+
+fn position<Name: PartialEq + 'static>(object: &UID, name: &Name) -> Option<usize> {
+    object.fields.iter().position(|f| f.name.downcast_ref::<Name>() == Some(name))
+}
+
+/// Adds a dynamic field to the object `object: &mut UID` at field specified by `name: Name`.
+/// Aborts with `EFieldAlreadyExists` if the object already has that field with that name.
+pub fn add<Name: PartialEq + 'static, Value: 'static>(object: &mut UID, name: Name, value: Value) {
+    abort::check(position(object, &name).is_none(), "dynamic_field", "add", EFieldAlreadyExists);
+    object.fields.push(Field { name: Box::new(name), value: Box::new(value) });
+}
+
+/// Immutably borrows the `object`s dynamic field with the name specified by `name: Name`.
+/// Aborts with `EFieldDoesNotExist` if the object does not have a field with that name.
+/// Aborts with `EFieldTypeMismatch` if the field exists, but the value does not have the specified
+/// type.
+pub fn borrow<Name: PartialEq + 'static, Value: 'static>(object: &UID, name: Name) -> &Value {
+    let i = position(object, &name);
+    abort::check(i.is_some(), "dynamic_field", "borrow", EFieldDoesNotExist);
+    let value = object.fields[i.unwrap()].value.downcast_ref::<Value>();
+    abort::check(value.is_some(), "dynamic_field", "borrow", EFieldTypeMismatch);
+    value.unwrap()
+}
+
+/// Mutably borrows the `object`s dynamic field with the name specified by `name: Name`.
+/// Aborts with `EFieldDoesNotExist` if the object does not have a field with that name.
+/// Aborts with `EFieldTypeMismatch` if the field exists, but the value does not have the specified
+/// type.
+pub fn borrow_mut<Name: PartialEq + 'static, Value: 'static>(object: &mut UID, name: Name) -> &mut Value {
+    let i = position(object, &name);
+    abort::check(i.is_some(), "dynamic_field", "borrow_mut", EFieldDoesNotExist);
+    let field = &mut object.fields[i.unwrap()];
+    abort::check(field.value.is::<Value>(), "dynamic_field", "borrow_mut", EFieldTypeMismatch);
+    field.value.downcast_mut::<Value>().unwrap()
+}
+
+/// Removes the `object`s dynamic field with the name specified by `name: Name` and returns the
+/// bound value.
+/// Aborts with `EFieldDoesNotExist` if the object does not have a field with that name.
+/// Aborts with `EFieldTypeMismatch` if the field exists, but the value does not have the specified
+/// type.
+pub fn remove<Name: PartialEq + 'static, Value: 'static>(object: &mut UID, name: Name) -> Value {
+    let i = position(object, &name);
+    abort::check(i.is_some(), "dynamic_field", "remove", EFieldDoesNotExist);
+    let i = i.unwrap();
+    abort::check(object.fields[i].value.is::<Value>(), "dynamic_field", "remove", EFieldTypeMismatch);
+    *object.fields.remove(i).value.downcast::<Value>().unwrap()
+}
+
+/// Returns true if and only if the `object` has a dynamic field with the name specified by
+/// `name: Name` but without specifying the `Value` type
+pub fn exists_<Name: PartialEq + 'static>(object: &UID, name: Name) -> bool {
+    position(object, &name).is_some()
+}
+
+/// Removes the dynamic field if it exists. Returns the `some(Value)` if it exists or none otherwise.
+pub fn remove_if_exists<Name: PartialEq + 'static, Value: 'static>(object: &mut UID, name: Name) -> Option<Value> {
+    if position(object, &name).is_some() {
+        Some(remove(object, name))
+    } else {
+        None
+    }
+}
+
+/// Returns true if and only if the `object` has a dynamic field with the name specified by
+/// `name: Name` with an assigned value of type `Value`.
+pub fn exists_with_type<Name: PartialEq + 'static, Value: 'static>(object: &UID, name: Name) -> bool {
+    position(object, &name).is_some_and(|i| object.fields[i].value.is::<Value>())
+}
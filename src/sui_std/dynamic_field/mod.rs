@@ -0,0 +1 @@
+pub(crate) mod dynamic_field;
\ No newline at end of file
@@ -0,0 +1,104 @@
+use std::any::Any;
+use crate::runtime::{abort, ledger};
+use crate::runtime::ledger::Owner;
+use crate::sui_std::dynamic_field::dynamic_field::{self, EFieldTypeMismatch};
+use crate::sui_std::object::object::{self, KeyObject, ID, UID};
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Similar to `sui::dynamic_field`, this module allows for the access of dynamic fields. But
+/// unlike, `sui::dynamic_field` the values bound to these dynamic fields _must_ be objects
+/// themselves. This allows for the objects to still exist within in storage, which may be important
+/// for external tools. The difference is otherwise not observable from within Move.
+pub struct sui__dynamic_object_field {}
+
+// Internal object used for storing the field and the name associated with the value
+// The separate type is necessary to prevent key collision with direct usage of dynamic_field
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Wrapper<Name> {
+    name: Name,
+}
+
+/// The child object, kept in the parent's dynamic fields under its `Wrapper`. Storage
+/// records the child as owned by the parent, see `ledger::attach`.
+struct Child {
+    id: ID,
+    object: Box<dyn Any>,
+}
+
+/// Adds a dynamic object field to the object `object: &mut UID` at field specified by `name: Name`.
+/// Aborts with `EFieldAlreadyExists` if the object already has that field with that name.
+pub fn add<Name: Clone + PartialEq + 'static, Value: KeyObject + 'static>(object: &mut UID, name: Name, value: Value) {
+    let id = object::id(&value);
+    dynamic_field::add(object, Wrapper { name }, Child { id, object: Box::new(value) });
+    ledger::attach(id, Owner::ObjectOwner(object::uid_to_inner(object)));
+}
+
+/// Immutably borrow the `object`s dynamic object field with the name specified by `name: Name`.
+/// Aborts with `EFieldDoesNotExist` if the object does not have a field with that name.
+/// Aborts with `EFieldTypeMismatch` if the field exists, but the value object does not have the
+/// specified type.
+pub fn borrow<Name: Clone + PartialEq + 'static, Value: 'static>(object: &UID, name: Name) -> &Value {
+    let child = dynamic_field::borrow::<Wrapper<Name>, Child>(object, Wrapper { name });
+    let value = child.object.downcast_ref::<Value>();
+    abort::check(value.is_some(), "dynamic_field", "borrow", EFieldTypeMismatch);
+    value.unwrap()
+}
+
+/// Mutably borrow the `object`s dynamic object field with the name specified by `name: Name`.
+/// Aborts with `EFieldDoesNotExist` if the object does not have a field with that name.
+/// Aborts with `EFieldTypeMismatch` if the field exists, but the value object does not have the
+/// specified type.
+pub fn borrow_mut<Name: Clone + PartialEq + 'static, Value: 'static>(object: &mut UID, name: Name) -> &mut Value {
+    let child = dynamic_field::borrow_mut::<Wrapper<Name>, Child>(object, Wrapper { name });
+    abort::check(child.object.is::<Value>(), "dynamic_field", "borrow_mut", EFieldTypeMismatch);
+    child.object.downcast_mut::<Value>().unwrap()
+}
+
+/// Removes the `object`s dynamic object field with the name specified by `name: Name` and returns
+/// the bound object.
+/// Aborts with `EFieldDoesNotExist` if the object does not have a field with that name.
+/// Aborts with `EFieldTypeMismatch` if the field exists, but the value object does not have the
+/// specified type.
+pub fn remove<Name: Clone + PartialEq + 'static, Value: 'static>(object: &mut UID, name: Name) -> Value {
+    let key = Wrapper { name };
+    let child = dynamic_field::borrow::<Wrapper<Name>, Child>(object, key.clone());
+    abort::check(child.object.is::<Value>(), "dynamic_field", "remove", EFieldTypeMismatch);
+    let Child { id, object: value } = dynamic_field::remove::<Wrapper<Name>, Child>(object, key);
+    ledger::remove(id);
+    *value.downcast::<Value>().unwrap()
+}
+
+/// Returns true if and only if the `object` has a dynamic object field with the name specified by
+/// `name: Name`.
+pub fn exists_<Name: Clone + PartialEq + 'static>(object: &UID, name: Name) -> bool {
+    dynamic_field::exists_with_type::<Wrapper<Name>, Child>(object, Wrapper { name })
+}
+
+/// Returns true if and only if the `object` has a dynamic field with the name specified by
+/// `name: Name` with an assigned value of type `Value`.
+pub fn exists_with_type<Name: Clone + PartialEq + 'static, Value: 'static>(object: &UID, name: Name) -> bool {
+    exists_(object, name.clone())
+        && dynamic_field::borrow::<Wrapper<Name>, Child>(object, Wrapper { name }).object.is::<Value>()
+}
+
+/// Returns the ID of the object associated with the dynamic object field
+/// Returns none otherwise
+pub fn id<Name: Clone + PartialEq + 'static>(object: &UID, name: Name) -> Option<ID> {
+    let key = Wrapper { name };
+    if !dynamic_field::exists_with_type::<Wrapper<Name>, Child>(object, key.clone()) {
+        return None;
+    }
+    Some(dynamic_field::borrow::<Wrapper<Name>, Child>(object, key).id)
+}
+
+// === verification ===
+
+/// The object holding `child` as a dynamic object field, if any.
+pub fn parent(child: &ID) -> Option<ID> {
+    match ledger::owner(*child) {
+        Some(Owner::ObjectOwner(parent)) => Some(parent),
+        _ => None,
+    }
+}
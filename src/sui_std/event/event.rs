@@ -0,0 +1,55 @@
+use crate::runtime::events;
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Events module. Defines the `sui::event::emit` function which
+/// creates and sends a custom MoveEvent as a part of the effects
+/// certificate of the transaction.
+///
+/// Every MoveEvent has the following properties:
+///  - sender
+///  - type signature (`T`)
+///  - event data (the value of `T`)
+///  - timestamp (local to a node)
+///  - transaction digest
+///
+/// Example:
+/// ```move
+/// module my::marketplace {
+///    use sui::event;
+///    /* ... */
+///    struct ItemPurchased has copy, drop {
+///      item_id: ID, buyer: address
+///    }
+///    entry fun buy(/* .... */) {
+///       /* ... */
+///       event::emit(ItemPurchased { item_id: ..., buyer: .... })
+///    }
+/// }
+/// ```
+pub struct sui__event {}
+
+/// Emit a custom Move event, sending the data offchain.
+///
+/// Used for creating custom indexes and tracking onchain
+/// activity in a way that suits a specific application the most.
+///
+/// The type `T` is the main way to index the event, and can contain
+/// phantom parameters, eg `emit(MyEvent<phantom T>)`.
+pub fn emit<T: 'static>(event: T) {
+    events::record(Box::new(event));
+}
+
+// === testing and verification ===
+
+/// Get the total number of events emitted during execution so far
+pub fn num_events() -> u32 {
+    events::count() as u32
+}
+
+/// Get all events of type `T` emitted during execution.
+/// Can only be used in testing,
+pub fn events_by_type<T: Clone + 'static>() -> Vec<T> {
+    events::by_type::<T>()
+}
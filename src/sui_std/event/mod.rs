@@ -0,0 +1 @@
+pub(crate) mod event;
\ No newline at end of file
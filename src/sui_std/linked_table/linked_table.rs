@@ -0,0 +1,213 @@
+use std::marker::PhantomData;
+use crate::runtime::{abort, arith};
+use crate::sui_std::dynamic_field::dynamic_field as field;
+use crate::sui_std::object::object::{self, KeyObject, UID};
+use crate::sui_std::tx_context::tx_context::TxContext;
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Similar to `sui::table` but the values are linked together, allowing for ordered insertion and
+/// removal
+pub struct sui__linked_table {}
+
+// Attempted to destroy a non-empty table
+pub const ETableNotEmpty: u64 = 0;
+// Attempted to remove the front or back of an empty table
+pub const ETableIsEmpty: u64 = 1;
+
+pub struct LinkedTable<K, V> {
+    /// the ID of this table
+    id: UID,
+    /// the number of key-value pairs in the table
+    size: u64,
+    /// the front of the table, i.e. the key of the first entry
+    head: Option<K>,
+    /// the back of the table, i.e. the key of the last entry
+    tail: Option<K>,
+    _type: PhantomData<V>,
+}
+
+impl<K, V> KeyObject for LinkedTable<K, V> {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+pub struct Node<K, V> {
+    /// the previous key
+    prev: Option<K>,
+    /// the next key
+    next: Option<K>,
+    /// the value being stored
+    value: V,
+}
+
+/// Creates a new, empty table
+pub fn new<K, V>(ctx: &mut TxContext) -> LinkedTable<K, V> {
+    LinkedTable {
+        id: object::new(ctx),
+        size: 0,
+        head: None,
+        tail: None,
+        _type: PhantomData,
+    }
+}
+
+/// Returns the key for the first element in the table, or None if the table is empty
+pub fn front<K, V>(table: &LinkedTable<K, V>) -> &Option<K> {
+    &table.head
+}
+
+/// Returns the key for the last element in the table, or None if the table is empty
+pub fn back<K, V>(table: &LinkedTable<K, V>) -> &Option<K> {
+    &table.tail
+}
+
+/// Inserts a key-value pair at the front of the table, i.e. the newly inserted pair will be
+/// the first element in the table
+/// Aborts with `sui::dynamic_field::EFieldAlreadyExists` if the table already has an entry with
+/// that key `k: K`.
+pub fn push_front<K: Clone + PartialEq + 'static, V: 'static>(table: &mut LinkedTable<K, V>, k: K, value: V) {
+    let old_head = table.head.replace(k.clone());
+    if table.tail.is_none() { table.tail = Some(k.clone()) };
+    let prev = None;
+    let next = match old_head {
+        Some(old_head_k) => {
+            field::borrow_mut::<K, Node<K, V>>(&mut table.id, old_head_k.clone()).prev = Some(k.clone());
+            Some(old_head_k)
+        }
+        None => None,
+    };
+    field::add(&mut table.id, k, Node { prev, next, value });
+    table.size = arith::add(table.size, 1);
+}
+
+/// Inserts a key-value pair at the back of the table, i.e. the newly inserted pair will be
+/// the last element in the table
+/// Aborts with `sui::dynamic_field::EFieldAlreadyExists` if the table already has an entry with
+/// that key `k: K`.
+pub fn push_back<K: Clone + PartialEq + 'static, V: 'static>(table: &mut LinkedTable<K, V>, k: K, value: V) {
+    if table.head.is_none() { table.head = Some(k.clone()) };
+    let old_tail = table.tail.replace(k.clone());
+    let prev = match old_tail {
+        Some(old_tail_k) => {
+            field::borrow_mut::<K, Node<K, V>>(&mut table.id, old_tail_k.clone()).next = Some(k.clone());
+            Some(old_tail_k)
+        }
+        None => None,
+    };
+    let next = None;
+    field::add(&mut table.id, k, Node { prev, next, value });
+    table.size = arith::add(table.size, 1);
+}
+
+/// Immutable borrows the value associated with the key in the table `table: &LinkedTable<K, V>`.
+/// Aborts with `sui::dynamic_field::EFieldDoesNotExist` if the table does not have an entry with
+/// that key `k: K`.
+pub fn borrow<K: PartialEq + 'static, V: 'static>(table: &LinkedTable<K, V>, k: K) -> &V {
+    &field::borrow::<K, Node<K, V>>(&table.id, k).value
+}
+
+/// Mutably borrows the value associated with the key in the table `table: &mut LinkedTable<K, V>`.
+/// Aborts with `sui::dynamic_field::EFieldDoesNotExist` if the table does not have an entry with
+/// that key `k: K`.
+pub fn borrow_mut<K: PartialEq + 'static, V: 'static>(table: &mut LinkedTable<K, V>, k: K) -> &mut V {
+    &mut field::borrow_mut::<K, Node<K, V>>(&mut table.id, k).value
+}
+
+/// Borrows the key for the previous entry of the specified key `k: K` in the table
+/// `table: &LinkedTable<K, V>`. Returns None if the entry does not have a predecessor.
+/// Aborts with `sui::dynamic_field::EFieldDoesNotExist` if the table does not have an entry with
+/// that key `k: K`
+pub fn prev<K: PartialEq + 'static, V: 'static>(table: &LinkedTable<K, V>, k: K) -> &Option<K> {
+    &field::borrow::<K, Node<K, V>>(&table.id, k).prev
+}
+
+/// Borrows the key for the next entry of the specified key `k: K` in the table
+/// `table: &LinkedTable<K, V>`. Returns None if the entry does not have a predecessor.
+/// Aborts with `sui::dynamic_field::EFieldDoesNotExist` if the table does not have an entry with
+/// that key `k: K`
+pub fn next<K: PartialEq + 'static, V: 'static>(table: &LinkedTable<K, V>, k: K) -> &Option<K> {
+    &field::borrow::<K, Node<K, V>>(&table.id, k).next
+}
+
+/// Removes the key-value pair in the table `table: &mut LinkedTable<K, V>` and returns the value.
+/// This splices the element out of the ordering.
+/// Aborts with `sui::dynamic_field::EFieldDoesNotExist` if the table does not have an entry with
+/// that key `k: K`. Note: this is also what happens when the table is empty.
+pub fn remove<K: Clone + PartialEq + 'static, V: 'static>(table: &mut LinkedTable<K, V>, k: K) -> V {
+    let Node { prev, next, value } = field::remove::<K, Node<K, V>>(&mut table.id, k.clone());
+    table.size = arith::sub(table.size, 1);
+    if let Some(prev_k) = &prev {
+        field::borrow_mut::<K, Node<K, V>>(&mut table.id, prev_k.clone()).next = next.clone();
+    }
+    if let Some(next_k) = &next {
+        field::borrow_mut::<K, Node<K, V>>(&mut table.id, next_k.clone()).prev = prev.clone();
+    }
+    if table.head.as_ref() == Some(&k) { table.head = next };
+    if table.tail.as_ref() == Some(&k) { table.tail = prev };
+    value
+}
+
+/// Removes the front of the table `table: &mut LinkedTable<K, V>` and returns the value.
+/// Aborts with `ETableIsEmpty` if the table is empty
+pub fn pop_front<K: Clone + PartialEq + 'static, V: 'static>(table: &mut LinkedTable<K, V>) -> (K, V) {
+    abort::check(table.head.is_some(), "linked_table", "pop_front", ETableIsEmpty);
+    let head = table.head.clone().unwrap();
+    (head.clone(), remove(table, head))
+}
+
+/// Removes the back of the table `table: &mut LinkedTable<K, V>` and returns the value.
+/// Aborts with `ETableIsEmpty` if the table is empty
+pub fn pop_back<K: Clone + PartialEq + 'static, V: 'static>(table: &mut LinkedTable<K, V>) -> (K, V) {
+    abort::check(table.tail.is_some(), "linked_table", "pop_back", ETableIsEmpty);
+    let tail = table.tail.clone().unwrap();
+    (tail.clone(), remove(table, tail))
+}
+
+/// Returns true iff there is a value associated with the key `k: K` in table
+/// `table: &LinkedTable<K, V>`
+pub fn contains<K: PartialEq + 'static, V: 'static>(table: &LinkedTable<K, V>, k: K) -> bool {
+    field::exists_with_type::<K, Node<K, V>>(&table.id, k)
+}
+
+/// Returns the size of the table, the number of key-value pairs
+pub fn length<K, V>(table: &LinkedTable<K, V>) -> u64 {
+    table.size
+}
+
+/// Returns true iff the table is empty (if `length` returns `0`)
+pub fn is_empty<K, V>(table: &LinkedTable<K, V>) -> bool {
+    table.size == 0
+}
+
+/// Destroys an empty table
+/// Aborts with `ETableNotEmpty` if the table still contains values
+pub fn destroy_empty<K, V>(table: LinkedTable<K, V>) {
+    let LinkedTable { id, size, head: _, tail: _, _type } = table;
+    abort::check(size == 0, "linked_table", "destroy_empty", ETableNotEmpty);
+    object::delete(id)
+}
+
+/// Drop a possibly non-empty table.
+/// Usable only if the value type `V` has the `drop` ability
+pub fn drop<K, V>(table: LinkedTable<K, V>) {
+    let LinkedTable { id, size: _, head: _, tail: _, _type } = table;
+    object::delete(id)
+}
+
+// === verification ===
+
+/// The keys from front to back, following `next`, stopping after at most `bound` of them so
+/// loops in harnesses stay within kani's unwinding.
+pub fn keys<K: Clone + PartialEq + 'static, V: 'static>(table: &LinkedTable<K, V>, bound: u64) -> Vec<K> {
+    let mut keys = Vec::new();
+    let mut current = table.head.clone();
+    while let Some(k) = current {
+        if keys.len() as u64 == bound {
+            break;
+        }
+        current = next(table, k.clone()).clone();
+        keys.push(k);
+    }
+    keys
+}
@@ -0,0 +1 @@
+pub(crate) mod linked_table;
\ No newline at end of file
@@ -1,4 +1,25 @@
+pub(crate) mod address;
+pub(crate) mod bag;
 pub(crate) mod balance;
+pub(crate) mod clock;
 pub(crate) mod coin;
+pub(crate) mod deny_list;
+pub(crate) mod dynamic_field;
+pub(crate) mod dynamic_object_field;
+pub(crate) mod event;
+pub(crate) mod linked_table;
+pub(crate) mod object;
+pub(crate) mod object_bag;
+pub(crate) mod object_table;
+pub(crate) mod option;
+pub(crate) mod pay;
+pub(crate) mod priority_queue;
+pub(crate) mod random;
+pub(crate) mod sui;
 pub(crate) mod transfer;
-pub(crate) mod table;
\ No newline at end of file
+pub(crate) mod table;
+pub(crate) mod table_vec;
+pub(crate) mod token;
+pub(crate) mod tx_context;
+pub(crate) mod vec_map;
+pub(crate) mod vec_set;
\ No newline at end of file
@@ -0,0 +1 @@
+pub(crate) mod object;
\ No newline at end of file
@@ -0,0 +1,171 @@
+use std::any::TypeId;
+use std::fmt;
+use crate::runtime::{ids, ledger};
+use crate::sui_std::address::address::{self, Address};
+use crate::sui_std::dynamic_field::dynamic_field::Field;
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Sui object identifiers
+pub struct sui__object {}
+
+/// An object ID. This is used to reference Sui Objects.
+/// This is *not* guaranteed to be globally unique--anyone can create an `ID` from a `UID` or
+/// from an object, and ID's can be freely copied and dropped.
+/// Here, the values are not globally unique because there can be multiple values of type `ID`
+/// with the same underlying bytes. For example, `object::id(&obj)` can be called as many times
+/// as you want for a given `obj`, and each `ID` value will be identical.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ID {
+    // Any address spells an `ID`, but objects only ever get the `u64`s
+    // `runtime::ids` hands out, see `tx_context::fresh_object_address`.
+    bytes: Address,
+}
+
+/// Globally unique IDs that define an object's ID in storage. Any Sui Object, that is a struct
+/// with the `key` ability, must have `id: UID` as its first field.
+/// These are globally unique in the sense that no two values of type `UID` are ever equal, in
+/// other words for any two values `id1: UID` and `id2: UID`, `id1` != `id2`.
+/// This is a privileged type that can only be derived from a `TxContext`.
+/// `UID` doesn't have the `drop` ability, so deleting a `UID` requires a call to `delete`.
+pub struct UID {
+    id: ID,
+    /// Dynamic fields attached to the object, see `dynamic_field`. On chain
+    /// they live in storage under the object's address; here they travel with
+    /// the `UID`, so borrowing a field borrows the object.
+    pub(crate) fields: Vec<Field>,
+}
+
+/// `UID`s are unique, equality is identity regardless of attached fields.
+impl PartialEq for UID {
+    fn eq(&self, other: &UID) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for UID {}
+
+impl fmt::Debug for UID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UID").field("id", &self.id).field("fields", &self.fields.len()).finish()
+    }
+}
+
+/// Structs with the `key` ability. Gives access to the `UID` every object
+/// carries in its `id` field, which `id` and `borrow_id` rely on.
+pub trait KeyObject {
+    fn uid(&self) -> &UID;
+
+    /// `T` for a `Coin<T>`, whose transfers validators check against the deny
+    /// list. `None` for every other object.
+    fn coin_type(&self) -> Option<TypeId> {
+        None
+    }
+}
+
+// === id ===
+
+/// Get the raw bytes of a `ID`
+pub fn id_to_bytes(id: &ID) -> Vec<u8> {
+    address::to_bytes(id_to_address(id))
+}
+
+/// Get the inner bytes of `id` as an address.
+pub fn id_to_address(id: &ID) -> Address {
+    id.bytes
+}
+
+/// Make an `ID` from raw bytes.
+pub fn id_from_bytes(bytes: Vec<u8>) -> ID {
+    id_from_address(address::from_bytes(bytes))
+}
+
+/// Make an `ID` from an address.
+pub fn id_from_address(bytes: Address) -> ID {
+    ID { bytes }
+}
+
+/// The raw ID `runtime::ids` allocated for `id`, `None` if no object can have it.
+fn allocated_id(id: &ID) -> Option<u64> {
+    address::to_u256(id.bytes).to_u128().and_then(|bytes| u64::try_from(bytes).ok())
+}
+
+// === uid ===
+
+/// Get the inner `ID` of `uid`
+pub fn uid_as_inner(uid: &UID) -> &ID {
+    &uid.id
+}
+
+/// Get the raw bytes of a `uid`'s inner `ID`
+pub fn uid_to_inner(uid: &UID) -> ID {
+    uid.id
+}
+
+/// Get the raw bytes of a `UID`
+pub fn uid_to_bytes(uid: &UID) -> Vec<u8> {
+    id_to_bytes(&uid.id)
+}
+
+/// Get the inner bytes of a `UID` as an address.
+pub fn uid_to_address(uid: &UID) -> Address {
+    id_to_address(&uid.id)
+}
+
+// === any object ===
+
+/// Create a new object. Returns the `UID` that must be stored in a Sui object.
+/// This is the only way to create `UID`s.
+pub fn new(ctx: &mut TxContext) -> UID {
+    UID {
+        id: ID { bytes: tx_context::fresh_object_address(ctx) },
+        fields: Vec::new(),
+    }
+}
+
+/// Delete the object and it's `UID`. This is the only way to eliminate a `UID`.
+/// This exists to inform Sui of object deletions. When an object
+/// gets unpacked, the programmer will have to do something with its
+/// `UID`. The implementation of this function emits a deleted
+/// system event so Sui knows to process the object deletion
+pub fn delete(id: UID) {
+    let UID { id, fields: _ } = id;
+    ledger::forget(id);
+    if let Some(bytes) = allocated_id(&id) {
+        ids::delete_id(bytes);
+    }
+}
+
+/// Get the underlying `ID` of `obj`
+pub fn id<T: KeyObject>(obj: &T) -> ID {
+    obj.uid().id
+}
+
+/// Borrow the underlying `ID` of `obj`
+pub fn borrow_id<T: KeyObject>(obj: &T) -> &ID {
+    &obj.uid().id
+}
+
+/// Get the raw bytes for the underlying `ID` of `obj`
+pub fn id_bytes<T: KeyObject>(obj: &T) -> Vec<u8> {
+    id_to_bytes(&obj.uid().id)
+}
+
+/// Get the inner bytes for the underlying `ID` of `obj`
+pub fn id_address<T: KeyObject>(obj: &T) -> Address {
+    id_to_address(&obj.uid().id)
+}
+
+// === verification ===
+
+/// Whether `id` belongs to an object that was created and not yet deleted.
+pub fn is_live(id: &ID) -> bool {
+    allocated_id(id).is_some_and(ids::is_live)
+}
+
+/// Whether `id` belongs to an object created in the transaction currently executing.
+pub fn is_new(id: &ID) -> bool {
+    allocated_id(id).is_some_and(ids::is_new)
+}
@@ -0,0 +1 @@
+pub(crate) mod object_bag;
\ No newline at end of file
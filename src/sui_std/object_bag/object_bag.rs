@@ -0,0 +1,107 @@
+use crate::runtime::{abort, arith};
+use crate::sui_std::dynamic_object_field::dynamic_object_field as ofield;
+use crate::sui_std::object::object::{self, KeyObject, ID, UID};
+use crate::sui_std::tx_context::tx_context::TxContext;
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Similar to `sui::bag`, an `ObjectBag` is a heterogeneous map-like collection. But unlike
+/// `sui::bag`, the values bound to these dynamic fields _must_ be objects themselves. This allows
+/// for the objects to still exist in storage, which may be important for external tools.
+/// The difference is otherwise not observable from within Move.
+pub struct sui__object_bag {}
+
+// Attempted to destroy a non-empty bag
+pub const EBagNotEmpty: u64 = 0;
+
+pub struct ObjectBag {
+    /// the ID of this bag
+    id: UID,
+    /// the number of key-value pairs in the bag
+    size: u64,
+}
+
+impl KeyObject for ObjectBag {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+/// Creates a new, empty bag
+pub fn new(ctx: &mut TxContext) -> ObjectBag {
+    ObjectBag {
+        id: object::new(ctx),
+        size: 0,
+    }
+}
+
+/// Adds a key-value pair to the bag `bag: &mut ObjectBag`
+/// Aborts with `sui::dynamic_field::EFieldAlreadyExists` if the bag already has an entry with
+/// that key `k: K`.
+pub fn add<K: Clone + PartialEq + 'static, V: KeyObject + 'static>(bag: &mut ObjectBag, k: K, v: V) {
+    ofield::add(&mut bag.id, k, v);
+    bag.size = arith::add(bag.size, 1);
+}
+
+/// Immutably borrow the value associated with the key in the bag `bag: &ObjectBag`.
+/// Aborts with `sui::dynamic_field::EFieldDoesNotExist` if the bag does not have an entry with
+/// that key `k: K`.
+/// Aborts with `sui::dynamic_field::EFieldTypeMismatch` if the bag has an entry for the key, but
+/// the value does not have the specified type.
+pub fn borrow<K: Clone + PartialEq + 'static, V: 'static>(bag: &ObjectBag, k: K) -> &V {
+    ofield::borrow(&bag.id, k)
+}
+
+/// Mutably borrow the value associated with the key in the bag `bag: &mut ObjectBag`.
+/// Aborts with `sui::dynamic_field::EFieldDoesNotExist` if the bag does not have an entry with
+/// that key `k: K`.
+/// Aborts with `sui::dynamic_field::EFieldTypeMismatch` if the bag has an entry for the key, but
+/// the value does not have the specified type.
+pub fn borrow_mut<K: Clone + PartialEq + 'static, V: 'static>(bag: &mut ObjectBag, k: K) -> &mut V {
+    ofield::borrow_mut(&mut bag.id, k)
+}
+
+/// Mutably borrow the key-value pair in the bag `bag: &mut ObjectBag` and returns the value.
+/// Aborts with `sui::dynamic_field::EFieldDoesNotExist` if the bag does not have an entry with
+/// that key `k: K`.
+/// Aborts with `sui::dynamic_field::EFieldTypeMismatch` if the bag has an entry for the key, but
+/// the value does not have the specified type.
+pub fn remove<K: Clone + PartialEq + 'static, V: 'static>(bag: &mut ObjectBag, k: K) -> V {
+    let v = ofield::remove(&mut bag.id, k);
+    bag.size = arith::sub(bag.size, 1);
+    v
+}
+
+/// Returns true iff there is an value associated with the key `k: K` in the bag `bag: &ObjectBag`
+pub fn contains<K: Clone + PartialEq + 'static>(bag: &ObjectBag, k: K) -> bool {
+    ofield::exists_(&bag.id, k)
+}
+
+/// Returns true iff there is an value associated with the key `k: K` in the bag `bag: &ObjectBag`
+/// with an assigned value of type `V`
+pub fn contains_with_type<K: Clone + PartialEq + 'static, V: 'static>(bag: &ObjectBag, k: K) -> bool {
+    ofield::exists_with_type::<K, V>(&bag.id, k)
+}
+
+/// Returns the size of the bag, the number of key-value pairs
+pub fn length(bag: &ObjectBag) -> u64 {
+    bag.size
+}
+
+/// Returns true iff the bag is empty (if `length` returns `0`)
+pub fn is_empty(bag: &ObjectBag) -> bool {
+    bag.size == 0
+}
+
+/// Destroys an empty object bag
+/// Aborts with `EBagNotEmpty` if the bag still contains values
+pub fn destroy_empty(bag: ObjectBag) {
+    let ObjectBag { id, size } = bag;
+    abort::check(size == 0, "object_bag", "destroy_empty", EBagNotEmpty);
+    object::delete(id)
+}
+
+/// Returns the ID of the object associated with the key if the bag has an entry with key `k: K`
+/// Returns none otherwise
+pub fn value_id<K: Clone + PartialEq + 'static>(bag: &ObjectBag, k: K) -> Option<ID> {
+    ofield::id(&bag.id, k)
+}
@@ -0,0 +1 @@
+pub(crate) mod object_table;
\ No newline at end of file
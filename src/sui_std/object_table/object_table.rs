@@ -0,0 +1,99 @@
+use std::marker::PhantomData;
+use crate::runtime::{abort, arith};
+use crate::sui_std::dynamic_object_field::dynamic_object_field as ofield;
+use crate::sui_std::object::object::{self, KeyObject, ID, UID};
+use crate::sui_std::tx_context::tx_context::TxContext;
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Similar to `sui::table`, an `ObjectTable<K, V>` is a map-like collection. But unlike
+/// `sui::table`, the values bound to these dynamic fields _must_ be objects themselves. This allows
+/// for the objects to still exist within in storage, which may be important for external tools.
+/// The difference is otherwise not observable from within Move.
+pub struct sui__object_table {}
+
+// Attempted to destroy a non-empty table
+pub const ETableNotEmpty: u64 = 0;
+
+pub struct ObjectTable<K, V> {
+    /// the ID of this table
+    id: UID,
+    /// the number of key-value pairs in the table
+    size: u64,
+    _type: PhantomData<(K, V)>,
+}
+
+impl<K, V> KeyObject for ObjectTable<K, V> {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+/// Creates a new, empty table
+pub fn new<K, V>(ctx: &mut TxContext) -> ObjectTable<K, V> {
+    ObjectTable {
+        id: object::new(ctx),
+        size: 0,
+        _type: PhantomData,
+    }
+}
+
+/// Adds a key-value pair to the table `table: &mut ObjectTable<K, V>`
+/// Aborts with `sui::dynamic_field::EFieldAlreadyExists` if the table already has an entry with
+/// that key `k: K`.
+pub fn add<K: Clone + PartialEq + 'static, V: KeyObject + 'static>(table: &mut ObjectTable<K, V>, k: K, v: V) {
+    ofield::add(&mut table.id, k, v);
+    table.size = arith::add(table.size, 1);
+}
+
+/// Immutable borrows the value associated with the key in the table `table: &ObjectTable<K, V>`.
+/// Aborts with `sui::dynamic_field::EFieldDoesNotExist` if the table does not have an entry with
+/// that key `k: K`.
+pub fn borrow<K: Clone + PartialEq + 'static, V: 'static>(table: &ObjectTable<K, V>, k: K) -> &V {
+    ofield::borrow(&table.id, k)
+}
+
+/// Mutably borrows the value associated with the key in the table `table: &mut ObjectTable<K, V>`.
+/// Aborts with `sui::dynamic_field::EFieldDoesNotExist` if the table does not have an entry with
+/// that key `k: K`.
+pub fn borrow_mut<K: Clone + PartialEq + 'static, V: 'static>(table: &mut ObjectTable<K, V>, k: K) -> &mut V {
+    ofield::borrow_mut(&mut table.id, k)
+}
+
+/// Removes the key-value pair in the table `table: &mut ObjectTable<K, V>` and returns the value.
+/// Aborts with `sui::dynamic_field::EFieldDoesNotExist` if the table does not have an entry with
+/// that key `k: K`.
+pub fn remove<K: Clone + PartialEq + 'static, V: 'static>(table: &mut ObjectTable<K, V>, k: K) -> V {
+    let v = ofield::remove(&mut table.id, k);
+    table.size = arith::sub(table.size, 1);
+    v
+}
+
+/// Returns true iff there is a value associated with the key `k: K` in table
+/// `table: &ObjectTable<K, V>`
+pub fn contains<K: Clone + PartialEq + 'static, V: 'static>(table: &ObjectTable<K, V>, k: K) -> bool {
+    ofield::exists_with_type::<K, V>(&table.id, k)
+}
+
+/// Returns the size of the table, the number of key-value pairs
+pub fn length<K, V>(table: &ObjectTable<K, V>) -> u64 {
+    table.size
+}
+
+/// Returns true iff the table is empty (if `length` returns `0`)
+pub fn is_empty<K, V>(table: &ObjectTable<K, V>) -> bool {
+    table.size == 0
+}
+
+/// Destroys an empty table
+/// Aborts with `ETableNotEmpty` if the table still contains values
+pub fn destroy_empty<K, V>(table: ObjectTable<K, V>) {
+    let ObjectTable { id, size, _type } = table;
+    abort::check(size == 0, "object_table", "destroy_empty", ETableNotEmpty);
+    object::delete(id)
+}
+
+/// Returns the ID of the object associated with the key if the table has an entry with key `k: K`
+/// Returns none otherwise
+pub fn value_id<K: Clone + PartialEq + 'static, V>(table: &ObjectTable<K, V>, k: K) -> Option<ID> {
+    ofield::id(&table.id, k)
+}
@@ -0,0 +1 @@
+pub(crate) mod option;
\ No newline at end of file
@@ -0,0 +1,16 @@
+// Copyright (c) The Diem Core Contributors
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module defines the Option type and its methods to represent and handle an optional value.
+///
+/// Move's `Option` is Rust's; only the abort codes are needed. The transpiler
+/// rewrites `option::fill` and `option::extract` into `abort::check`s with them.
+pub struct std__option {}
+
+/// The `Option` is in an invalid state for the operation attempted.
+/// The `Option` is `Some` while it should be `None`.
+pub const EOPTION_IS_SET: u64 = 0x40000;
+/// The `Option` is in an invalid state for the operation attempted.
+/// The `Option` is `None` while it should be `Some`.
+pub const EOPTION_NOT_SET: u64 = 0x40001;
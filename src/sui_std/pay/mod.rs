@@ -0,0 +1 @@
+pub(crate) mod pay;
\ No newline at end of file
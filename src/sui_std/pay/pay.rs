@@ -0,0 +1,77 @@
+use crate::runtime::{abort, arith};
+use crate::sui_std::address::address::Address;
+use crate::sui_std::coin::coin::{self, Coin};
+use crate::sui_std::transfer::transfer;
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module provides handy functionality for wallets and `sui::Coin` management.
+pub struct sui__pay {}
+
+/// For when empty vector is supplied into join function.
+pub const ENoCoins: u64 = 0;
+
+/// Transfer `c` to the sender of the current transaction
+pub fn keep<T: 'static>(c: Coin<T>, ctx: &TxContext) {
+    transfer::pub_transfer(c, tx_context::sender(ctx))
+}
+
+/// Split coin `self` to two coins, one with balance `split_amount`,
+/// and the remaining balance is left is `self`.
+pub fn split<T: 'static>(coin: &mut Coin<T>, split_amount: u64, ctx: &mut TxContext) {
+    keep(coin::split(coin, split_amount, ctx), ctx)
+}
+
+/// Split coin `self` into multiple coins, each with balance specified
+/// in `split_amounts`. Remaining balance is left in `self`.
+pub fn split_vec<T: 'static>(this: &mut Coin<T>, split_amounts: Vec<u64>, ctx: &mut TxContext) {
+    let (mut i, len) = (0, split_amounts.len() as u64);
+    while i < len {
+        split(this, split_amounts[i as usize], ctx);
+        i = arith::add(i, 1);
+    };
+}
+
+/// Send `amount` units of `c` to `recipient`
+/// Aborts with `sui::balance::ENotEnough` if `amount` is greater than the balance in `c`
+pub fn split_and_transfer<T: 'static>(c: &mut Coin<T>, amount: u64, recipient: Address, ctx: &mut TxContext) {
+    transfer::pub_transfer(coin::split(c, amount, ctx), recipient)
+}
+
+/// Divide coin `self` into `n - 1` coins with equal balances. If the balance is
+/// not evenly divisible by `n`, the remainder is left in `self`.
+pub fn divide_and_keep<T: 'static>(this: &mut Coin<T>, n: u64, ctx: &mut TxContext) {
+    let mut vec: Vec<Coin<T>> = coin::divide_into_n(this, n, ctx);
+    let (mut i, len) = (0, vec.len() as u64);
+    while i < len {
+        transfer::pub_transfer(vec.pop().unwrap(), tx_context::sender(ctx));
+        i = arith::add(i, 1);
+    };
+}
+
+/// Join `coin` into `self`. Re-exports `coin::join` function.
+/// Deprecated: you should call `coin.join(other)` directly.
+pub fn join<T>(this: &mut Coin<T>, coin: Coin<T>) {
+    coin::join(this, coin)
+}
+
+/// Join everything in `coins` with `self`
+pub fn join_vec<T>(this: &mut Coin<T>, mut coins: Vec<Coin<T>>) {
+    let (mut i, len) = (0, coins.len() as u64);
+    while i < len {
+        let coin = coins.pop().unwrap();
+        coin::join(this, coin);
+        i = arith::add(i, 1);
+    };
+}
+
+/// Join a vector of `Coin` into a single object and transfer it to `receiver`.
+pub fn join_vec_and_transfer<T: 'static>(mut coins: Vec<Coin<T>>, receiver: Address) {
+    abort::check(!coins.is_empty(), "pay", "join_vec_and_transfer", ENoCoins);
+
+    let mut this = coins.pop().unwrap();
+    join_vec(&mut this, coins);
+    transfer::pub_transfer(this, receiver)
+}
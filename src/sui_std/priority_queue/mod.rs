@@ -0,0 +1 @@
+pub(crate) mod priority_queue;
\ No newline at end of file
@@ -0,0 +1,115 @@
+use crate::runtime::{abort, arith};
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Priority queue implemented using a max heap.
+pub struct sui__priority_queue {}
+
+/// For when heap is empty and there's no data to pop.
+pub const EPopFromEmptyHeap: u64 = 0;
+
+/// Struct representing a priority queue. The `entries` vector represents a max
+/// heap structure, where entries[0] is the root, entries[1] and entries[2] are the
+/// left child and right child of the root, etc. More generally, the children of
+/// entries[i] are at i * 2 + 1 and i * 2 + 2. The max heap should have the invariant
+/// that the parent node's priority is always higher than its child nodes' priorities.
+pub struct PriorityQueue<T> {
+    entries: Vec<Entry<T>>,
+}
+
+pub struct Entry<T> {
+    priority: u64, // higher value means higher priority and will be popped first
+    value: T,
+}
+
+/// Create a new priority queue from the input entry vectors.
+pub fn new<T>(mut entries: Vec<Entry<T>>) -> PriorityQueue<T> {
+    let len = entries.len() as u64;
+    let mut i = arith::div(len, 2);
+    // Max heapify from the first node that is a parent (node at len / 2).
+    while i > 0 {
+        i = arith::sub(i, 1);
+        max_heapify_recursive(&mut entries, len, i);
+    };
+    PriorityQueue { entries }
+}
+
+/// Pop the entry with the highest priority value.
+pub fn pop_max<T>(pq: &mut PriorityQueue<T>) -> (u64, T) {
+    let len = pq.entries.len() as u64;
+    abort::check(len > 0, "priority_queue", "pop_max", EPopFromEmptyHeap);
+    // Swap the max element with the last element in the entries and remove the max element.
+    let Entry { priority, value } = pq.entries.swap_remove(0);
+    // Now the max heap property has been violated at the root node, but nowhere else
+    // so we call heapify on the root node.
+    max_heapify_recursive(&mut pq.entries, arith::sub(len, 1), 0);
+    (priority, value)
+}
+
+/// Insert a new entry into the queue.
+pub fn insert<T>(pq: &mut PriorityQueue<T>, priority: u64, value: T) {
+    pq.entries.push(Entry { priority, value });
+    let index = arith::sub(pq.entries.len() as u64, 1);
+    restore_heap_recursive(&mut pq.entries, index);
+}
+
+pub fn new_entry<T>(priority: u64, value: T) -> Entry<T> {
+    Entry { priority, value }
+}
+
+pub fn create_entries<T>(p: Vec<u64>, v: Vec<T>) -> Vec<Entry<T>> {
+    let len = p.len();
+    abort::check(v.len() == len, "priority_queue", "create_entries", 0);
+    p.into_iter().zip(v).map(|(priority, value)| new_entry(priority, value)).collect()
+}
+
+// TODO: implement iterative version too and see performance difference.
+fn restore_heap_recursive<T>(v: &mut Vec<Entry<T>>, i: u64) {
+    if i == 0 {
+        return
+    };
+    let parent = arith::div(arith::sub(i, 1), 2);
+
+    // If new elem is greater than its parent, swap them and recursively
+    // do the restoration upwards.
+    if v[i as usize].priority > v[parent as usize].priority {
+        v.swap(i as usize, parent as usize);
+        restore_heap_recursive(v, parent);
+    }
+}
+
+/// Max heapify the subtree whose root is at index `i`. That means after this function
+/// finishes, the subtree should have the property that the parent node has higher priority
+/// than both child nodes.
+/// This function assumes that all the other nodes in the subtree (nodes other than the root)
+/// do satisfy the max heap property.
+fn max_heapify_recursive<T>(v: &mut Vec<Entry<T>>, len: u64, i: u64) {
+    if len == 0 {
+        return
+    };
+    abort::check(i < len, "priority_queue", "max_heapify_recursive", 1);
+    let left = arith::add(arith::mul(i, 2), 1);
+    let right = arith::add(left, 1);
+    let mut max = i;
+    // Find the node with highest priority among node `i` and its two children.
+    if left < len && v[left as usize].priority > v[max as usize].priority {
+        max = left;
+    };
+    if right < len && v[right as usize].priority > v[max as usize].priority {
+        max = right;
+    };
+    // If the parent node (node `i`) doesn't have the highest priority, we swap the parent with the
+    // max priority node.
+    if max != i {
+        v.swap(max as usize, i as usize);
+        // After the swap, we have restored the property at node `i` but now the max heap property
+        // may be violated at node `max` since this node now has a new value. So we need to now
+        // max heapify the subtree rooted at node `max`.
+        max_heapify_recursive(v, len, max);
+    }
+}
+
+pub fn priorities<T>(pq: &PriorityQueue<T>) -> Vec<u64> {
+    pq.entries.iter().map(|entry| entry.priority).collect()
+}
@@ -0,0 +1 @@
+pub(crate) mod random;
\ No newline at end of file
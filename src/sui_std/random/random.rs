@@ -0,0 +1,206 @@
+use crate::runtime::{abort, arith};
+use crate::runtime::u256::U256;
+use crate::sui_std::address::address;
+use crate::sui_std::object::object::{self, KeyObject, UID};
+use crate::sui_std::transfer::transfer;
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module provides functionality for generating secure randomness.
+pub struct sui__random {}
+
+// Sender is not @0x0 the system address.
+pub const ENotSystemAddress: u64 = 0;
+pub const EWrongInnerVersion: u64 = 1;
+pub const EInvalidRandomnessUpdate: u64 = 2;
+pub const EInvalidRange: u64 = 3;
+pub const EInvalidLength: u64 = 4;
+
+/// Singleton shared object which stores the global randomness state.
+/// The actual state is stored in a versioned inner field.
+///
+/// Here the state is the seed concrete generators start from. Under kani the
+/// seed is ignored and every generated value is nondeterministic.
+pub struct Random {
+    id: UID,
+    randomness_round: u64,
+    random_bytes: Vec<u8>,
+}
+
+impl KeyObject for Random {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+/// Unique randomness generator, derived from the global randomness.
+///
+/// Concretely a splitmix64 stream keyed by the global randomness and a fresh
+/// object address, so runs are reproducible for a given seed.
+pub struct RandomGenerator {
+    seed: u64,
+    counter: u64,
+}
+
+/// Create a generator. Can be used to derive up to MAX_U16 * 32 random bytes.
+///
+/// Using randomness can be error-prone if you don't observe the subtleties in its correct use, for example, randomness
+/// dependent code might be exploitable to attacks that carefully set the gas budget
+/// in a way that breaks security. For more information, see:
+/// https://docs.sui.io/guides/developer/advanced/randomness-onchain
+pub fn new_generator(r: &Random, ctx: &mut TxContext) -> RandomGenerator {
+    let mut seed = 0u64;
+    for byte in r.random_bytes.iter().chain(address::to_bytes(tx_context::fresh_object_address(ctx)).iter()) {
+        seed = splitmix64(seed ^ *byte as u64);
+    }
+    RandomGenerator { seed, counter: 0 }
+}
+
+/// The next 64 random bits of `g`.
+#[cfg(not(kani))]
+fn next_u64(g: &mut RandomGenerator) -> u64 {
+    g.counter = arith::add(g.counter, 1);
+    splitmix64(g.seed.wrapping_add(g.counter))
+}
+
+#[cfg(kani)]
+fn next_u64(g: &mut RandomGenerator) -> u64 {
+    g.counter = arith::add(g.counter, 1);
+    kani::any()
+}
+
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+/// Generate n random bytes.
+pub fn generate_bytes(g: &mut RandomGenerator, num_of_bytes: u16) -> Vec<u8> {
+    (0..num_of_bytes).map(|_| next_u64(g) as u8).collect()
+}
+
+/// Generate a u256.
+pub fn generate_u256(g: &mut RandomGenerator) -> U256 {
+    let hi = (next_u64(g) as u128) << 64 | next_u64(g) as u128;
+    let lo = (next_u64(g) as u128) << 64 | next_u64(g) as u128;
+    U256::from_limbs(hi, lo)
+}
+
+/// Generate a u128.
+pub fn generate_u128(g: &mut RandomGenerator) -> u128 {
+    (next_u64(g) as u128) << 64 | next_u64(g) as u128
+}
+
+/// Generate a u64.
+pub fn generate_u64(g: &mut RandomGenerator) -> u64 {
+    next_u64(g)
+}
+
+/// Generate a u32.
+pub fn generate_u32(g: &mut RandomGenerator) -> u32 {
+    next_u64(g) as u32
+}
+
+/// Generate a u16.
+pub fn generate_u16(g: &mut RandomGenerator) -> u16 {
+    next_u64(g) as u16
+}
+
+/// Generate a u8.
+pub fn generate_u8(g: &mut RandomGenerator) -> u8 {
+    next_u64(g) as u8
+}
+
+/// Generate a boolean.
+pub fn generate_bool(g: &mut RandomGenerator) -> bool {
+    next_u64(g) & 1 == 1
+}
+
+/// A value in `[min, max]`, `function` names the caller for the abort.
+fn u128_in_range(g: &mut RandomGenerator, min: u128, max: u128, function: &'static str) -> u128 {
+    abort::check(min <= max, "random", function, EInvalidRange);
+    in_range(g, min, max)
+}
+
+#[cfg(not(kani))]
+fn in_range(g: &mut RandomGenerator, min: u128, max: u128) -> u128 {
+    let value = generate_u128(g);
+    match (max - min).checked_add(1) {
+        Some(range_size) => min + value % range_size,
+        None => value,
+    }
+}
+
+#[cfg(kani)]
+fn in_range(g: &mut RandomGenerator, min: u128, max: u128) -> u128 {
+    g.counter = arith::add(g.counter, 1);
+    kani::any_where(|value: &u128| min <= *value && *value <= max)
+}
+
+/// Generate a random u128 in [min, max] (with a bias of 2^{-64}).
+pub fn generate_u128_in_range(g: &mut RandomGenerator, min: u128, max: u128) -> u128 {
+    u128_in_range(g, min, max, "generate_u128_in_range")
+}
+
+/// Generate a random u64 in [min, max] (with a bias of 2^{-64}).
+pub fn generate_u64_in_range(g: &mut RandomGenerator, min: u64, max: u64) -> u64 {
+    u128_in_range(g, min as u128, max as u128, "generate_u64_in_range") as u64
+}
+
+/// Generate a random u32 in [min, max] (with a bias of 2^{-64}).
+pub fn generate_u32_in_range(g: &mut RandomGenerator, min: u32, max: u32) -> u32 {
+    u128_in_range(g, min as u128, max as u128, "generate_u32_in_range") as u32
+}
+
+/// Generate a random u16 in [min, max] (with a bias of 2^{-64}).
+pub fn generate_u16_in_range(g: &mut RandomGenerator, min: u16, max: u16) -> u16 {
+    u128_in_range(g, min as u128, max as u128, "generate_u16_in_range") as u16
+}
+
+/// Generate a random u8 in [min, max] (with a bias of 2^{-64}).
+pub fn generate_u8_in_range(g: &mut RandomGenerator, min: u8, max: u8) -> u8 {
+    u128_in_range(g, min as u128, max as u128, "generate_u8_in_range") as u8
+}
+
+/// Shuffle a vector using the random generator (Fisher–Yates/Knuth shuffle).
+pub fn shuffle<T>(g: &mut RandomGenerator, v: &mut [T]) {
+    let n = v.len() as u64;
+    if n == 0 {
+        return
+    };
+    abort::check(n <= u16::MAX as u64, "random", "shuffle", EInvalidLength);
+    let n = n as u16;
+    let mut i: u16 = 0;
+    let end = n - 1;
+    while i < end {
+        let j = generate_u16_in_range(g, i, end);
+        v.swap(i as usize, j as usize);
+        i = arith::add(i, 1);
+    };
+}
+
+// === testing and verification ===
+
+/// Create and share the `Random` object, as genesis does, starting from an all-zero seed.
+pub fn create_for_testing(ctx: &mut TxContext) {
+    transfer::share_object(Random {
+        id: object::new(ctx),
+        randomness_round: 0,
+        random_bytes: vec![0u8; 32],
+    });
+}
+
+/// Move to the next randomness round, e.g. to replay a concrete run with another seed.
+pub fn update_randomness_state_for_testing(r: &mut Random, new_round: u64, new_bytes: Vec<u8>) {
+    // Randomness should only be incremented.
+    abort::check(
+        new_round == arith::add(r.randomness_round, 1),
+        "random",
+        "update_randomness_state",
+        EInvalidRandomnessUpdate,
+    );
+    r.randomness_round = new_round;
+    r.random_bytes = new_bytes;
+}
@@ -0,0 +1 @@
+pub(crate) mod sui;
\ No newline at end of file
@@ -0,0 +1,19 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Coin<SUI> is the token used to pay for gas in Sui.
+/// It has 9 decimals, and the smallest unit (10^-9) is called "mist".
+pub struct sui__sui {}
+
+/// The amount of Mist per Sui token based on the fact that mist is
+/// 10^-9 of a Sui token
+const MIST_PER_SUI: u64 = 1_000_000_000;
+
+/// The total supply of Sui denominated in whole Sui tokens (10 Billion)
+const TOTAL_SUPPLY_SUI: u64 = 10_000_000_000;
+
+/// The total supply of Sui denominated in Mist (10 Billion * 10^9)
+const TOTAL_SUPPLY_MIST: u64 = 10_000_000_000_000_000_000;
+
+/// Name of the coin
+pub struct SUI {}
@@ -1,38 +1,34 @@
 
 use std::ops::{Index, IndexMut};
-use std::sync::LazyLock;
 use std::collections::HashMap;
 use std::hash::Hash;
+use crate::runtime::{abort, arith};
+use crate::sui_std::object::object::{self, KeyObject, UID};
+use crate::sui_std::tx_context::tx_context::TxContext;
 
 
-pub struct IdGetter {
-    current_id: std::sync::Mutex<u8>,
-}
+// Tables are built on dynamic fields, so missing and duplicate keys abort there.
 
-impl IdGetter {
-    pub fn new() -> Self {
-        IdGetter {
-            current_id: std::sync::Mutex::new(0),
-        }
-    }
+/// The key is already in the table.
+pub const EFieldAlreadyExists: u64 = 0;
 
-    pub fn get_new_id(&self) -> u8 {
-        let mut id = self.current_id.lock().unwrap();
-        *id += 1;
-        *id
-    }
-}
+/// The key is not in the table.
+pub const EFieldDoesNotExist: u64 = 1;
 
-// Use LazyLock to initialize ID_GETTER
-pub static ID_GETTER: LazyLock<IdGetter> = LazyLock::new(|| IdGetter::new());
+/// For when trying to destroy a non-empty table.
+pub const ETableNotEmpty: u64 = 0;
 
 pub trait Key: Eq + Hash {}
 impl<T> Key for T where T: Eq + Hash {}
 
 pub struct Table<K: Key, V> {
-    pub id: u8,
+    pub id: UID,
     pub map: HashMap<K, V>,
-    pub size: u8,
+    pub size: u64,
+}
+
+impl<K: Key, V> KeyObject for Table<K, V> {
+    fn uid(&self) -> &UID { &self.id }
 }
 
 impl<K: Key, V> Index<&K> for Table<K, V> {
@@ -51,42 +47,34 @@ impl<K: Key, V> IndexMut<&K> for Table<K, V> {
     }
 }
 
-pub fn new<K: Key, V>() -> Table<K, V> {
+pub fn new<K: Key, V>(ctx: &mut TxContext) -> Table<K, V> {
     Table{
-        id: ID_GETTER.get_new_id(),
+        id: object::new(ctx),
         map: HashMap::new(),
         size: 0
     }
 }
 
 pub fn add<K: Key,V>(t: &mut Table<K, V>, key: K, value: V) -> () {
-    if contains(t, &key) {
-        panic!("Key already exists in Table");
-    }
+    abort::check(!contains(t, &key), "dynamic_field", "add", EFieldAlreadyExists);
     t.map.insert(key, value);
-    t.size = t.size + 1;
+    t.size = arith::add(t.size, 1);
 }
 
-pub fn borrow<'a, K: Key, V>(t: &'a Table<K, V>, key: &'a K) -> &'a V {
-    if !contains(t, &key) {
-        panic!("Key missing from Table");
-    }
+pub fn borrow<'a, K: Key, V>(t: &'a Table<K, V>, key: &K) -> &'a V {
+    abort::check(contains(t, key), "dynamic_field", "borrow", EFieldDoesNotExist);
     t.map.get(key).unwrap()
 }
 
-pub fn borrow_mut<'a, K: Key, V>(t: &'a mut Table<K, V>, key: &'a K) -> &'a mut V {
-    if !contains(t, &key) {
-        panic!("Key missing from Table");
-    }
+pub fn borrow_mut<'a, K: Key, V>(t: &'a mut Table<K, V>, key: &K) -> &'a mut V {
+    abort::check(contains(t, key), "dynamic_field", "borrow_mut", EFieldDoesNotExist);
     t.map.get_mut(key).unwrap()
 }
 
 pub fn remove<'a, K: Key, V>(t: &'a mut Table<K, V>, key: &'a K) -> V {
-    if !contains(t, &key) {
-        panic!("Key missing from Table");
-    }
+    abort::check(contains(t, key), "dynamic_field", "remove", EFieldDoesNotExist);
     let value: V = t.map.remove(key).unwrap();
-    t.size = t.size - 1;
+    t.size = arith::sub(t.size, 1);
     value
 }
 
@@ -94,7 +82,7 @@ pub fn contains<K: Key, V>(t: &Table<K, V>, key: &K) -> bool {
     t.map.contains_key(key)
 }
 
-pub fn length<K: Key, V>(t: &Table<K, V>) -> u8 {
+pub fn length<K: Key, V>(t: &Table<K, V>) -> u64 {
     t.size
 }
 
@@ -102,9 +90,13 @@ pub fn is_empty<K: Key, V>(t: &Table<K, V>) -> bool {
     t.size == 0
 }
 
-pub fn destroy_empty<K: Key, V>(t: &Table<K, V>) -> () {
-    assert!(is_empty(t));
+pub fn destroy_empty<K: Key, V>(t: Table<K, V>) -> () {
+    abort::check(is_empty(&t), "table", "destroy_empty", ETableNotEmpty);
+    let Table { id, map: _, size: _ } = t;
+    object::delete(id);
 }
 
-pub fn drop<K: Key, V>(t: &Table<K, V>) -> () {
+pub fn drop<K: Key, V>(t: Table<K, V>) -> () {
+    let Table { id, map: _, size: _ } = t;
+    object::delete(id);
 }
\ No newline at end of file
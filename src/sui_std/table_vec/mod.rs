@@ -0,0 +1 @@
+pub(crate) mod table_vec;
\ No newline at end of file
@@ -0,0 +1,106 @@
+use crate::runtime::{abort, arith};
+use crate::sui_std::table::table::{self, Table};
+use crate::sui_std::tx_context::tx_context::TxContext;
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// A basic scalable vector library implemented using `Table`.
+pub struct sui__table_vec {}
+
+pub struct TableVec<Element> {
+    /// The contents of the table vector.
+    contents: Table<u64, Element>,
+}
+
+pub const EIndexOutOfBound: u64 = 0;
+pub const ETableNonEmpty: u64 = 1;
+
+/// Create an empty TableVec.
+pub fn empty<Element>(ctx: &mut TxContext) -> TableVec<Element> {
+    TableVec {
+        contents: table::new(ctx),
+    }
+}
+
+/// Return a TableVec of size one containing element `e`.
+pub fn singleton<Element>(e: Element, ctx: &mut TxContext) -> TableVec<Element> {
+    let mut t = empty(ctx);
+    push_back(&mut t, e);
+    t
+}
+
+/// Return the length of the TableVec.
+pub fn length<Element>(t: &TableVec<Element>) -> u64 {
+    table::length(&t.contents)
+}
+
+/// Return if the TableVec is empty or not.
+pub fn is_empty<Element>(t: &TableVec<Element>) -> bool {
+    length(t) == 0
+}
+
+/// Acquire an immutable reference to the `i`th element in the TableVec `t`.
+/// Aborts if `i` is out of bounds.
+pub fn borrow<Element>(t: &TableVec<Element>, i: u64) -> &Element {
+    abort::check(i < length(t), "table_vec", "borrow", EIndexOutOfBound);
+    table::borrow(&t.contents, &i)
+}
+
+/// Add element `e` to the end of the TableVec `t`.
+pub fn push_back<Element>(t: &mut TableVec<Element>, e: Element) {
+    let key = length(t);
+    table::add(&mut t.contents, key, e);
+}
+
+/// Return a mutable reference to the `i`th element in the TableVec `t`.
+/// Aborts if `i` is out of bounds.
+pub fn borrow_mut<Element>(t: &mut TableVec<Element>, i: u64) -> &mut Element {
+    abort::check(i < length(t), "table_vec", "borrow_mut", EIndexOutOfBound);
+    table::borrow_mut(&mut t.contents, &i)
+}
+
+/// Pop an element from the end of TableVec `t`.
+/// Aborts if `t` is empty.
+pub fn pop_back<Element>(t: &mut TableVec<Element>) -> Element {
+    let length = length(t);
+    abort::check(length > 0, "table_vec", "pop_back", EIndexOutOfBound);
+    table::remove(&mut t.contents, &arith::sub(length, 1))
+}
+
+/// Destroy the TableVec `t`.
+/// Aborts if `t` is not empty.
+pub fn destroy_empty<Element>(t: TableVec<Element>) {
+    abort::check(length(&t) == 0, "table_vec", "destroy_empty", ETableNonEmpty);
+    let TableVec { contents } = t;
+    table::destroy_empty(contents);
+}
+
+/// Drop a possibly non-empty TableVec `t`.
+/// Usable only if the value type `Element` has the `drop` ability
+pub fn drop<Element>(t: TableVec<Element>) {
+    let TableVec { contents } = t;
+    table::drop(contents)
+}
+
+/// Swaps the elements at the `i`th and `j`th indices in the TableVec `t`.
+/// Aborts if `i` or `j` is out of bounds.
+pub fn swap<Element>(t: &mut TableVec<Element>, i: u64, j: u64) {
+    abort::check(length(t) > i, "table_vec", "swap", EIndexOutOfBound);
+    abort::check(length(t) > j, "table_vec", "swap", EIndexOutOfBound);
+    if i == j { return };
+    let element_i = table::remove(&mut t.contents, &i);
+    let element_j = table::remove(&mut t.contents, &j);
+    table::add(&mut t.contents, j, element_i);
+    table::add(&mut t.contents, i, element_j);
+}
+
+/// Swap the `i`th element of the TableVec `t` with the last element and then pop the TableVec.
+/// This is O(1), but does not preserve ordering of elements in the TableVec.
+/// Aborts if `i` is out of bounds.
+pub fn swap_remove<Element>(t: &mut TableVec<Element>, i: u64) -> Element {
+    abort::check(length(t) > i, "table_vec", "swap_remove", EIndexOutOfBound);
+    let last_idx = arith::sub(length(t), 1);
+    swap(t, i, last_idx);
+    pop_back(t)
+}
@@ -0,0 +1 @@
+pub(crate) mod token;
\ No newline at end of file
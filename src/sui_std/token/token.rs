@@ -0,0 +1,661 @@
+use std::any::TypeId;
+use std::marker::PhantomData;
+use crate::runtime::abort;
+use crate::sui_std::address::address::Address;
+use crate::sui_std::balance::balance::{self, Balance};
+use crate::sui_std::coin::coin::{self, Coin, TreasuryCap};
+use crate::sui_std::dynamic_field::dynamic_field as df;
+use crate::sui_std::event::event;
+use crate::sui_std::object::object::{self, KeyObject, ID, UID};
+use crate::sui_std::transfer::transfer;
+use crate::sui_std::tx_context::tx_context::{self, TxContext};
+use crate::sui_std::vec_map::vec_map::{self, VecMap};
+use crate::sui_std::vec_set::vec_set::{self, VecSet};
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// The Token module which implements a Closed Loop Token with a configurable
+/// policy. The policy is defined by a set of rules that must be satisfied for
+/// an action to be performed on the token.
+///
+/// The module is designed to be used with a `TreasuryCap` to allow for minting
+/// and burning of the `Token`s. And can act as a replacement / extension or a
+/// companion to existing open-loop (`Coin`) systems.
+///
+/// ```text
+/// Module:      sui::balance       sui::coin             sui::token
+/// Main type:   Balance<T>         Coin<T>               Token<T>
+/// Capability:  Supply<T>  <---->  TreasuryCap<T> <----> TreasuryCap<T>
+/// Abilities:   store              key + store           key
+/// ```
+///
+/// The Token system allows for fine-grained control over the actions performed
+/// on the token. And hence it is highly suitable for applications that require
+/// control over the currency which a simple open-loop system can't provide.
+pub struct sui__token {}
+
+/// The action is not allowed (defined) in the policy.
+pub const EUnknownAction: u64 = 0;
+/// The rule was not approved.
+pub const ENotApproved: u64 = 1;
+/// Trying to perform an admin action with a wrong cap.
+pub const ENotAuthorized: u64 = 2;
+/// The balance is too low to perform the action.
+pub const EBalanceTooLow: u64 = 3;
+/// The balance is not zero.
+pub const ENotZero: u64 = 4;
+/// The balance is not zero when trying to confirm with `TransferPolicyCap`.
+pub const ECantConsumeBalance: u64 = 5;
+/// Rule is trying to access a missing config (with type).
+pub const ENoConfig: u64 = 6;
+/// Using `confirm_request_mut` without `spent_balance`. Immutable version
+/// of the function must be used instead.
+pub const EUseImmutableConfirm: u64 = 7;
+
+// === Protected Actions ===
+
+/// A Tag for the `spend` action.
+pub const SPEND: &str = "spend";
+/// A Tag for the `transfer` action.
+pub const TRANSFER: &str = "transfer";
+/// A Tag for the `to_coin` action.
+pub const TO_COIN: &str = "to_coin";
+/// A Tag for the `from_coin` action.
+pub const FROM_COIN: &str = "from_coin";
+
+/// Move's `std::type_name::TypeName`: rules are identified by the type of
+/// their witness.
+pub type TypeName = TypeId;
+
+fn type_name<W: 'static>() -> TypeName {
+    TypeId::of::<W>()
+}
+
+/// A single `Token` with `Balance` inside. Can only be owned by an address,
+/// and actions performed on it must be confirmed in a matching `TokenPolicy`.
+pub struct Token<T> {
+    id: UID,
+    /// The Balance of the `Token`.
+    balance: Balance<T>,
+}
+
+/// A Capability that manages a single `TokenPolicy` specified in the `for`
+/// field. Created together with `TokenPolicy` in the `new` function.
+pub struct TokenPolicyCap<T> {
+    id: UID,
+    for_: ID,
+    _type: PhantomData<T>,
+}
+
+/// `TokenPolicy` represents a set of rules that define what actions can be
+/// performed on a `Token` and which `Rules` must be satisfied for the
+/// action to succeed.
+///
+/// - For the sake of availability, `TokenPolicy` is a `key`-only object.
+/// - Each `TokenPolicy` is managed by a matching `TokenPolicyCap`.
+/// - For an action to become available, there needs to be a record in the
+/// `rules` VecMap. To allow an action to be performed freely, there's an
+/// `allow` function that can be called by the `TokenPolicyCap` owner.
+pub struct TokenPolicy<T> {
+    id: UID,
+    /// The balance that is effectively spent by the user on the "spend"
+    /// action. However, actual decrease of the supply can only be done by
+    /// the `TreasuryCap` owner when `flush` is called.
+    ///
+    /// This balance is effectively spent and cannot be accessed by anyone
+    /// but the `TreasuryCap` owner.
+    spent_balance: Balance<T>,
+    /// The set of rules that define what actions can be performed on the
+    /// token. For each "action" there's a set of Rules that must be
+    /// satisfied for the `ActionRequest` to be confirmed.
+    rules: VecMap<String, VecSet<TypeName>>,
+}
+
+impl<T> KeyObject for Token<T> {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+impl<T> KeyObject for TokenPolicyCap<T> {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+impl<T> KeyObject for TokenPolicy<T> {
+    fn uid(&self) -> &UID { &self.id }
+}
+
+/// A request to perform an "Action" on a token. Stores the information
+/// about the action to be performed and must be consumed by the `confirm_request`
+/// or `confirm_request_mut` functions when the Rules are satisfied.
+#[must_use = "an ActionRequest is a hot potato, it must be confirmed"]
+pub struct ActionRequest<T> {
+    /// Name of the Action to look up in the Policy. Name can be one of the
+    /// default actions: `transfer`, `spend`, `to_coin`, `from_coin` or a
+    /// custom action.
+    name: String,
+    /// Amount is present in all of the txs
+    amount: u64,
+    /// Sender is a permanent field always
+    sender: Address,
+    /// Recipient is only available in `transfer` action.
+    recipient: Option<Address>,
+    /// The balance to be "spent" in the `TokenPolicy`, only available
+    /// in the `spend` action.
+    spent_balance: Option<Balance<T>>,
+    /// Collected approvals (stamps) from completed `Rules`. They're matched
+    /// against `TokenPolicy.rules` to determine if the request can be
+    /// confirmed.
+    approvals: VecSet<TypeName>,
+}
+
+/// Dynamic field key for the `TokenPolicy` to store the `Config` for a
+/// specific action `Rule`. There can be only one configuration per
+/// `Rule` per `TokenPolicy`.
+pub struct RuleKey<T> {
+    is_protected: bool,
+    _type: PhantomData<T>,
+}
+
+impl<T> PartialEq for RuleKey<T> {
+    fn eq(&self, other: &RuleKey<T>) -> bool {
+        self.is_protected == other.is_protected
+    }
+}
+
+/// An event emitted when a `TokenPolicy` is created and shared. Because
+/// `TokenPolicy` can only be shared (and potentially frozen in the future),
+/// we emit this event in the `share_policy` function and mark it as mutable.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenPolicyCreated<T> {
+    /// ID of the `TokenPolicy` that was created.
+    id: ID,
+    /// Whether the `TokenPolicy` is "shared" (mutable) or "frozen"
+    /// (immutable) - TBD.
+    is_mutable: bool,
+    _type: PhantomData<T>,
+}
+
+/// Create a new `TokenPolicy` and a matching `TokenPolicyCap`.
+/// The `TokenPolicy` must then be shared using the `share_policy` method.
+///
+/// `TreasuryCap` guarantees full ownership over the currency, and is unique,
+/// hence it is safe to use it for authorization.
+pub fn new_policy<T>(_treasury_cap: &TreasuryCap<T>, ctx: &mut TxContext) -> (TokenPolicy<T>, TokenPolicyCap<T>) {
+    let policy = TokenPolicy {
+        id: object::new(ctx),
+        spent_balance: balance::zero(),
+        rules: vec_map::empty(),
+    };
+
+    let cap = TokenPolicyCap {
+        id: object::new(ctx),
+        for_: object::id(&policy),
+        _type: PhantomData,
+    };
+
+    (policy, cap)
+}
+
+/// Share the `TokenPolicy`. Due to `key`-only restriction, it must be
+/// shared after initialization.
+pub fn share_policy<T: 'static>(policy: TokenPolicy<T>) {
+    event::emit(TokenPolicyCreated::<T> {
+        id: object::id(&policy),
+        is_mutable: true,
+        _type: PhantomData,
+    });
+
+    transfer::share_object(policy)
+}
+
+// === Protected Actions ===
+
+/// Transfer a `Token` to a `recipient`. Creates an `ActionRequest` for the
+/// "transfer" action. The `ActionRequest` contains the `recipient` field
+/// to be used in verification.
+pub fn transfer<T: 'static>(t: Token<T>, recipient: Address, ctx: &mut TxContext) -> ActionRequest<T> {
+    let amount = balance::value(&t.balance);
+    transfer::transfer(t, recipient);
+
+    new_request(transfer_action(), amount, Some(recipient), None, ctx)
+}
+
+/// Spend a `Token` by unwrapping it and storing the `Balance` in the
+/// `ActionRequest` for the "spend" action. The `ActionRequest` contains
+/// the `spent_balance` field to be used in verification.
+///
+/// Spend action requires `confirm_request_mut` to be called to confirm the
+/// request and join the spent balance with the `TokenPolicy.spent_balance`.
+pub fn spend<T>(t: Token<T>, ctx: &mut TxContext) -> ActionRequest<T> {
+    let Token { id, balance } = t;
+    object::delete(id);
+
+    new_request(spend_action(), balance::value(&balance), None, Some(balance), ctx)
+}
+
+/// Convert `Token` into an open `Coin`. Creates an `ActionRequest` for the
+/// "to_coin" action.
+pub fn to_coin<T>(t: Token<T>, ctx: &mut TxContext) -> (Coin<T>, ActionRequest<T>) {
+    let Token { id, balance } = t;
+    let amount = balance::value(&balance);
+    object::delete(id);
+
+    (coin::from_balance(balance, ctx), new_request(to_coin_action(), amount, None, None, ctx))
+}
+
+/// Convert an open `Coin` into a `Token`. Creates an `ActionRequest` for
+/// the "from_coin" action.
+pub fn from_coin<T>(coin: Coin<T>, ctx: &mut TxContext) -> (Token<T>, ActionRequest<T>) {
+    let amount = coin::value(&coin);
+    let token = Token {
+        id: object::new(ctx),
+        balance: coin::into_balance(coin),
+    };
+
+    (token, new_request(from_coin_action(), amount, None, None, ctx))
+}
+
+// === Public Actions ===
+
+/// Join two `Token`s into one, always available.
+pub fn join<T>(token: &mut Token<T>, another: Token<T>) {
+    let Token { id, balance } = another;
+    balance::join(&mut token.balance, balance);
+    object::delete(id);
+}
+
+/// Split a `Token` with `amount`.
+/// Aborts if the `Token.balance` is lower than `amount`.
+pub fn split<T>(token: &mut Token<T>, amount: u64, ctx: &mut TxContext) -> Token<T> {
+    abort::check(balance::value(&token.balance) >= amount, "token", "split", EBalanceTooLow);
+    Token {
+        id: object::new(ctx),
+        balance: balance::split(&mut token.balance, amount),
+    }
+}
+
+/// Create a zero `Token`.
+pub fn zero<T>(ctx: &mut TxContext) -> Token<T> {
+    Token {
+        id: object::new(ctx),
+        balance: balance::zero(),
+    }
+}
+
+/// Destroy an empty `Token`, fails if the balance is non-zero.
+/// Aborts if the `Token.balance` is not zero.
+pub fn destroy_zero<T>(token: Token<T>) {
+    let Token { id, balance } = token;
+    abort::check(balance::value(&balance) == 0, "token", "destroy_zero", ENotZero);
+    balance::destroy_zero(balance);
+    object::delete(id);
+}
+
+/// Transfer the `Token` to the transaction sender.
+pub fn keep<T: 'static>(token: Token<T>, ctx: &mut TxContext) {
+    transfer::transfer(token, tx_context::sender(ctx))
+}
+
+// === Request Handling ===
+
+/// Create a new `ActionRequest`.
+/// Publicly available method to allow for custom actions.
+pub fn new_request<T>(
+    name: String,
+    amount: u64,
+    recipient: Option<Address>,
+    spent_balance: Option<Balance<T>>,
+    ctx: &TxContext,
+) -> ActionRequest<T> {
+    ActionRequest {
+        name,
+        amount,
+        recipient,
+        spent_balance,
+        sender: tx_context::sender(ctx),
+        approvals: vec_set::empty(),
+    }
+}
+
+/// Confirm the request against the `TokenPolicy` and return the parameters
+/// of the request: (Name, Amount, Sender, Recipient).
+///
+/// Cannot be used for `spend` and similar actions that deliver `spent_balance`
+/// to the `TokenPolicy`. For those actions use `confirm_request_mut`.
+///
+/// Aborts if:
+/// - the action is not allowed (missing record in `rules`)
+/// - action contains `spent_balance` (use `confirm_request_mut`)
+/// - the `ActionRequest` does not meet the `TokenPolicy` rules for the action
+pub fn confirm_request<T>(
+    policy: &TokenPolicy<T>,
+    request: ActionRequest<T>,
+    _ctx: &mut TxContext,
+) -> (String, u64, Address, Option<Address>) {
+    abort::check(request.spent_balance.is_none(), "token", "confirm_request", ECantConsumeBalance);
+    abort::check(vec_map::contains(&policy.rules, &request.name), "token", "confirm_request", EUnknownAction);
+
+    let ActionRequest { name, approvals, spent_balance: _, amount, sender, recipient } = request;
+
+    let rules = vec_set::keys(vec_map::get(&policy.rules, &name));
+    let approvals = vec_set::into_keys(approvals);
+    for rule in rules {
+        abort::check(approvals.contains(rule), "token", "confirm_request", ENotApproved);
+    }
+
+    (name, amount, sender, recipient)
+}
+
+/// Confirm the request against the `TokenPolicy` and return the parameters
+/// of the request: (Name, Amount, Sender, Recipient).
+///
+/// Unlike `confirm_request` this function requires mutable access to the
+/// `TokenPolicy` and must be used on `spend` action. After dealing with the
+/// spent balance it calls `confirm_request` internally.
+///
+/// See `confirm_request` for the list of abort conditions.
+pub fn confirm_request_mut<T>(
+    policy: &mut TokenPolicy<T>,
+    mut request: ActionRequest<T>,
+    ctx: &mut TxContext,
+) -> (String, u64, Address, Option<Address>) {
+    abort::check(vec_map::contains(&policy.rules, &request.name), "token", "confirm_request_mut", EUnknownAction);
+    abort::check(request.spent_balance.is_some(), "token", "confirm_request_mut", EUseImmutableConfirm);
+
+    balance::join(&mut policy.spent_balance, request.spent_balance.take().unwrap());
+
+    confirm_request(policy, request, ctx)
+}
+
+/// Confirm an `ActionRequest` as the `TokenPolicyCap` owner. This function
+/// allows `TokenPolicy` owner to perform Capability-gated actions ignoring
+/// the ruleset specified in the `TokenPolicy`.
+///
+/// Aborts if request contains `spent_balance` due to inability of the
+/// `TokenPolicyCap` to decrease supply. For scenarios like this a
+/// `TreasuryCap` is required (see `confirm_with_treasury_cap`).
+pub fn confirm_with_policy_cap<T>(
+    _policy_cap: &TokenPolicyCap<T>,
+    request: ActionRequest<T>,
+    _ctx: &mut TxContext,
+) -> (String, u64, Address, Option<Address>) {
+    abort::check(request.spent_balance.is_none(), "token", "confirm_with_policy_cap", ECantConsumeBalance);
+
+    let ActionRequest { name, amount, sender, recipient, approvals: _, spent_balance: _ } = request;
+
+    (name, amount, sender, recipient)
+}
+
+/// Confirm an `ActionRequest` as the `TreasuryCap` owner. This function
+/// allows `TreasuryCap` owner to perform Capability-gated actions ignoring
+/// the ruleset specified in the `TokenPolicy`.
+///
+/// Unlike `confirm_with_policy_cap` this function allows `spent_balance`
+/// to be consumed, decreasing the `total_supply` of the `Token`.
+pub fn confirm_with_treasury_cap<T>(
+    treasury_cap: &mut TreasuryCap<T>,
+    request: ActionRequest<T>,
+    _ctx: &mut TxContext,
+) -> (String, u64, Address, Option<Address>) {
+    let ActionRequest { name, amount, sender, recipient, approvals: _, spent_balance } = request;
+
+    if let Some(spent_balance) = spent_balance {
+        coin::supply_mut(treasury_cap).decrease_supply(spent_balance);
+    }
+
+    (name, amount, sender, recipient)
+}
+
+// === Rules API ===
+
+/// Add an "approval" to the `ActionRequest` by providing a Witness.
+/// Intended to be used by Rules to add their own approvals, however, can
+/// be used to add arbitrary approvals to the request (not only the ones
+/// required by the `TokenPolicy`).
+pub fn add_approval<T, W: 'static>(_t: W, request: &mut ActionRequest<T>, _ctx: &mut TxContext) {
+    vec_set::insert(&mut request.approvals, type_name::<W>())
+}
+
+/// Add a `Config` for a `Rule` in the `TokenPolicy`. Rule configuration is
+/// independent from the `TokenPolicy.rules` and needs to be managed by the
+/// Rule itself. Configuration is stored per `Rule` and not per `Rule` per
+/// `Action` to allow reuse in different actions.
+///
+/// - Rule witness guarantees that the `Config` is approved by the Rule.
+/// - `TokenPolicyCap` guarantees that the `Config` action is approved by the
+/// owner of the `TokenPolicy`.
+pub fn add_rule_config<T, Rule: 'static, Config: 'static>(
+    _rule: Rule,
+    policy: &mut TokenPolicy<T>,
+    cap: &TokenPolicyCap<T>,
+    config: Config,
+    _ctx: &mut TxContext,
+) {
+    abort::check(object::id(policy) == cap.for_, "token", "add_rule_config", ENotAuthorized);
+    df::add(&mut policy.id, key::<Rule>(), config)
+}
+
+/// Get a `Config` for a `Rule` in the `TokenPolicy`. Requires `Rule`
+/// witness, hence can only be read by the `Rule` itself. This requirement
+/// guarantees safety of the stored `Config` and allows for simpler dynamic
+/// field management inside the Rule Config (custom type keys are not needed
+/// for access gating).
+///
+/// Aborts if the Config is not present.
+pub fn rule_config<T, Rule: 'static, Config: 'static>(_rule: Rule, policy: &TokenPolicy<T>) -> &Config {
+    abort::check(has_rule_config_with_type::<T, Rule, Config>(policy), "token", "rule_config", ENoConfig);
+    df::borrow(&policy.id, key::<Rule>())
+}
+
+/// Get mutable access to the `Config` for a `Rule` in the `TokenPolicy`.
+/// Requires `Rule` witness, hence can only be read by the `Rule` itself,
+/// as well as `TokenPolicyCap` to guarantee that the `TokenPolicy` owner
+/// is the one who initiated the `Config` modification.
+///
+/// Aborts if:
+/// - the Config is not present
+/// - `TokenPolicyCap` is not matching the `TokenPolicy`
+pub fn rule_config_mut<'a, T, Rule: 'static, Config: 'static>(
+    _rule: Rule,
+    policy: &'a mut TokenPolicy<T>,
+    cap: &TokenPolicyCap<T>,
+) -> &'a mut Config {
+    abort::check(has_rule_config_with_type::<T, Rule, Config>(policy), "token", "rule_config_mut", ENoConfig);
+    abort::check(object::id(policy) == cap.for_, "token", "rule_config_mut", ENotAuthorized);
+    df::borrow_mut(&mut policy.id, key::<Rule>())
+}
+
+/// Remove a `Config` for a `Rule` in the `TokenPolicy`.
+/// Unlike the `add_rule_config`, this function does not require a `Rule`
+/// witness, hence can be performed by the `TokenPolicy` owner on their own.
+///
+/// Rules need to make sure that the `Config` is present when performing
+/// verification of the `ActionRequest`.
+///
+/// Aborts if:
+/// - the Config is not present
+/// - `TokenPolicyCap` is not matching the `TokenPolicy`
+pub fn remove_rule_config<T, Rule: 'static, Config: 'static>(
+    policy: &mut TokenPolicy<T>,
+    cap: &TokenPolicyCap<T>,
+    _ctx: &mut TxContext,
+) -> Config {
+    abort::check(has_rule_config_with_type::<T, Rule, Config>(policy), "token", "remove_rule_config", ENoConfig);
+    abort::check(object::id(policy) == cap.for_, "token", "remove_rule_config", ENotAuthorized);
+    df::remove(&mut policy.id, key::<Rule>())
+}
+
+/// Check if a config for a `Rule` is set in the `TokenPolicy` without
+/// checking the type of the `Config`.
+pub fn has_rule_config<T, Rule: 'static>(policy: &TokenPolicy<T>) -> bool {
+    df::exists_(&policy.id, key::<Rule>())
+}
+
+/// Check if a `Config` for a `Rule` is set in the `TokenPolicy` and that
+/// it matches the type provided.
+pub fn has_rule_config_with_type<T, Rule: 'static, Config: 'static>(policy: &TokenPolicy<T>) -> bool {
+    df::exists_with_type::<RuleKey<Rule>, Config>(&policy.id, key::<Rule>())
+}
+
+// === Protected: Setting Rules ===
+
+/// Allows an `action` to be performed on the `Token` freely by adding an
+/// empty set of `Rules` for the `action`.
+///
+/// Aborts if the `TokenPolicyCap` is not matching the `TokenPolicy`.
+pub fn allow<T>(policy: &mut TokenPolicy<T>, cap: &TokenPolicyCap<T>, action: String, _ctx: &mut TxContext) {
+    abort::check(object::id(policy) == cap.for_, "token", "allow", ENotAuthorized);
+    vec_map::insert(&mut policy.rules, action, vec_set::empty());
+}
+
+/// Completely disallows an `action` on the `Token` by removing the record
+/// from the `TokenPolicy.rules`.
+///
+/// Aborts if the `TokenPolicyCap` is not matching the `TokenPolicy`.
+pub fn disallow<T>(policy: &mut TokenPolicy<T>, cap: &TokenPolicyCap<T>, action: String, _ctx: &mut TxContext) {
+    abort::check(object::id(policy) == cap.for_, "token", "disallow", ENotAuthorized);
+    vec_map::remove(&mut policy.rules, &action);
+}
+
+/// Adds a Rule for an action with `name` in the `TokenPolicy`.
+///
+/// Aborts if the `TokenPolicyCap` is not matching the `TokenPolicy`.
+pub fn add_rule_for_action<T, Rule: 'static>(
+    policy: &mut TokenPolicy<T>,
+    cap: &TokenPolicyCap<T>,
+    action: String,
+    ctx: &mut TxContext,
+) {
+    abort::check(object::id(policy) == cap.for_, "token", "add_rule_for_action", ENotAuthorized);
+    if !vec_map::contains(&policy.rules, &action) {
+        allow(policy, cap, action.clone(), ctx);
+    };
+
+    vec_set::insert(vec_map::get_mut(&mut policy.rules, &action), type_name::<Rule>())
+}
+
+/// Removes a rule for an action with `name` in the `TokenPolicy`. Returns
+/// the config object to be handled by the sender (or a Rule itself).
+///
+/// Aborts if the `TokenPolicyCap` is not matching the `TokenPolicy`.
+pub fn remove_rule_for_action<T, Rule: 'static>(
+    policy: &mut TokenPolicy<T>,
+    cap: &TokenPolicyCap<T>,
+    action: String,
+    _ctx: &mut TxContext,
+) {
+    abort::check(object::id(policy) == cap.for_, "token", "remove_rule_for_action", ENotAuthorized);
+
+    vec_set::remove(vec_map::get_mut(&mut policy.rules, &action), &type_name::<Rule>())
+}
+
+// === Protected: Treasury Management ===
+
+/// Mint a `Token` with a given `amount` using the `TreasuryCap`.
+pub fn mint<T>(cap: &mut TreasuryCap<T>, amount: u64, ctx: &mut TxContext) -> Token<T> {
+    let balance = coin::supply_mut(cap).increase_supply(amount);
+    Token { id: object::new(ctx), balance }
+}
+
+/// Burn a `Token` using the `TreasuryCap`.
+pub fn burn<T>(cap: &mut TreasuryCap<T>, token: Token<T>) {
+    let Token { id, balance } = token;
+    coin::supply_mut(cap).decrease_supply(balance);
+    object::delete(id);
+}
+
+/// Flush the `TokenPolicy.spent_balance` into the `TreasuryCap`. This
+/// action is only available to the `TreasuryCap` owner.
+pub fn flush<T>(policy: &mut TokenPolicy<T>, cap: &mut TreasuryCap<T>, _ctx: &mut TxContext) -> u64 {
+    let amount = balance::value(&policy.spent_balance);
+    let balance = balance::split(&mut policy.spent_balance, amount);
+    coin::supply_mut(cap).decrease_supply(balance)
+}
+
+// === Getters: `TokenPolicy` and `Token` ===
+
+/// Check whether an action is present in the rules VecMap.
+pub fn is_allowed<T>(policy: &TokenPolicy<T>, action: &String) -> bool {
+    vec_map::contains(&policy.rules, action)
+}
+
+/// Returns the rules required for a specific action.
+pub fn rules<T>(policy: &TokenPolicy<T>, action: &String) -> VecSet<TypeName> {
+    vec_map::get(&policy.rules, action).clone()
+}
+
+/// Returns the `spent_balance` of the `TokenPolicy`.
+pub fn spent_balance<T>(policy: &TokenPolicy<T>) -> u64 {
+    balance::value(&policy.spent_balance)
+}
+
+/// Returns the `balance` of the `Token`.
+pub fn value<T>(t: &Token<T>) -> u64 {
+    balance::value(&t.balance)
+}
+
+// === Action Names ===
+
+/// Name of the Transfer action.
+pub fn transfer_action() -> String {
+    TRANSFER.to_string()
+}
+
+/// Name of the `Spend` action.
+pub fn spend_action() -> String {
+    SPEND.to_string()
+}
+
+/// Name of the `ToCoin` action.
+pub fn to_coin_action() -> String {
+    TO_COIN.to_string()
+}
+
+/// Name of the `FromCoin` action.
+pub fn from_coin_action() -> String {
+    FROM_COIN.to_string()
+}
+
+// === Action Request Fields  ==
+
+/// The Action in the `ActionRequest`.
+pub fn action<T>(request: &ActionRequest<T>) -> String {
+    request.name.clone()
+}
+
+/// Amount of the `ActionRequest`.
+pub fn amount<T>(request: &ActionRequest<T>) -> u64 {
+    request.amount
+}
+
+/// Sender of the `ActionRequest`.
+pub fn sender<T>(request: &ActionRequest<T>) -> Address {
+    request.sender
+}
+
+/// Recipient of the `ActionRequest`.
+pub fn recipient<T>(request: &ActionRequest<T>) -> Option<Address> {
+    request.recipient
+}
+
+/// Approvals of the `ActionRequest`.
+pub fn approvals<T>(request: &ActionRequest<T>) -> VecSet<TypeName> {
+    request.approvals.clone()
+}
+
+/// Burned balance of the `ActionRequest`.
+pub fn spent<T>(request: &ActionRequest<T>) -> Option<u64> {
+    request.spent_balance.as_ref().map(balance::value)
+}
+
+// === Internal ===
+
+/// Create a new `RuleKey` for a `Rule`. The `is_protected` field is kept
+/// for potential future use, if Rules were to have a freely modifiable
+/// storage as addition / replacement for the `Config` system.
+///
+/// The goal of `is_protected` is to potentially allow Rules store a mutable
+/// version of their configuration and mutate state on user action.
+fn key<Rule>() -> RuleKey<Rule> {
+    RuleKey { is_protected: true, _type: PhantomData }
+}
@@ -1,84 +1,64 @@
-use crate::sui_std::transfer::transfer;
+use std::marker::PhantomData;
+use crate::runtime::{abort, deny, ledger};
+use crate::sui_std::address::address::Address;
+use crate::sui_std::object::object::{self, KeyObject, ID, UID};
 
+pub use crate::runtime::ledger::Owner;
 
-use std::sync::LazyLock;
-
-pub struct IdGetter {
-    current_id: std::sync::Mutex<u8>,
-}
-
-impl IdGetter {
-    pub fn new() -> Self {
-        IdGetter {
-            current_id: std::sync::Mutex::new(0),
-        }
-    }
-
-    pub fn get_new_id(&self) -> u8 {
-        let mut id = self.current_id.lock().unwrap();
-        *id += 1;
-        *id
-    }
-}
-
-// Use LazyLock to initialize ID_GETTER
-pub static ID_GETTER: LazyLock<IdGetter> = LazyLock::new(|| IdGetter::new());
 
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-#[allow(unused_const)]
 pub struct sui__transfer {}
 
 /// This represents the ability to `receive` an object of type `T`.
 /// This type is ephemeral per-transaction and cannot be stored on-chain.
 /// This does not represent the obligation to receive the object that it
-/// references, but simply the ability to receive the object with object u8
+/// references, but simply the ability to receive the object with object ID
 /// `id` at version `version` if you can prove mutable access to the parent
 /// object during the transaction.
 /// Internals of this struct are opaque outside this module.
-pub struct Receiving {
-    id: u8,
+pub struct Receiving<T> {
+    id: ID,
     version: u64,
+    _object: PhantomData<T>,
 }
 
 /// Shared an object that was previously created. Shared objects must currently
 /// be constructed in the transaction they are created.
-const ESharedNonNewObject: u64 = 0;
+pub const ESharedNonNewObject: u64 = 0;
 
-#[allow(unused_const)]
 /// Serialization of the object failed.
-const EBCSSerializationFailure: u64 = 1;
+pub const EBCSSerializationFailure: u64 = 1;
 
-#[allow(unused_const)]
 /// The object being received is not of the expected type.
-const EReceivingObjectTypeMismatch: u64 = 2;
+pub const EReceivingObjectTypeMismatch: u64 = 2;
 
-#[allow(unused_const)]
 /// Represents both the case where the object does not exist and the case where the object is not
 /// able to be accessed through the parent that is passed-in.
-const EUnableToReceiveObject: u64 = 3;
+pub const EUnableToReceiveObject: u64 = 3;
 
-#[allow(unused_const)]
 /// Shared object operations such as wrapping, freezing, and converting to owned are not allowed.
-const ESharedObjectOperationNotSupported: u64 = 4;
+pub const ESharedObjectOperationNotSupported: u64 = 4;
 
 /// Transfer ownership of `obj` to `recipient`. `obj` must have the `key` attribute,
-/// which (in turn) ensures that `obj` has a globally unique u8. Note that if the recipient
-/// String represents an object u8, the `obj` sent will be inaccessible after the transfer
+/// which (in turn) ensures that `obj` has a globally unique ID. Note that if the recipient
+/// address represents an object ID, the `obj` sent will be inaccessible after the transfer
 /// (though they will be retrievable at a future date once new features are added).
 /// This fnction has custom rules performed by the Sui Move bytecode verifier that ensures
 /// that `T` is an object defined in the module where `transfer` is invoked. Use
 /// `pub_transfer` to transfer an object with `store` outside of its module.
-pub fn transfer<T>(obj: T, recipient: String) {
+pub fn transfer<T: KeyObject + 'static>(obj: T, recipient: Address) {
+    transfer_impl(obj, recipient)
 }
 
 /// Transfer ownership of `obj` to `recipient`. `obj` must have the `key` attribute,
-/// which (in turn) ensures that `obj` has a globally unique u8. Note that if the recipient
-/// String represents an object u8, the `obj` sent will be inaccessible after the transfer
+/// which (in turn) ensures that `obj` has a globally unique ID. Note that if the recipient
+/// address represents an object ID, the `obj` sent will be inaccessible after the transfer
 /// (though they will be retrievable at a future date once new features are added).
 /// The object must have `store` to be transferred outside of its module.
-pub fn pub_transfer<T>(obj: T, recipient: String) {
+pub fn pub_transfer<T: KeyObject + 'static>(obj: T, recipient: Address) {
+    transfer_impl(obj, recipient)
 }
 
 /// Freeze `obj`. After freezing `obj` becomes immutable and can no longer be transferred or
@@ -86,13 +66,15 @@ pub fn pub_transfer<T>(obj: T, recipient: String) {
 /// This fnction has custom rules performed by the Sui Move bytecode verifier that ensures
 /// that `T` is an object defined in the module where `freeze_object` is invoked. Use
 /// `pub_freeze_object` to freeze an object with `store` outside of its module.
-pub fn freeze_object<T>(obj: T) {
+pub fn freeze_object<T: KeyObject + 'static>(obj: T) {
+    freeze_object_impl(obj)
 }
 
 /// Freeze `obj`. After freezing `obj` becomes immutable and can no longer be transferred or
 /// mutated.
 /// The object must have `store` to be frozen outside of its module.
-pub fn pub_freeze_object<T>(obj: T) {
+pub fn pub_freeze_object<T: KeyObject + 'static>(obj: T) {
+    freeze_object_impl(obj)
 }
 
 /// Turn the given object into a mutable shared object that everyone can access and mutate.
@@ -102,7 +84,8 @@ pub fn pub_freeze_object<T>(obj: T) {
 /// This fnction has custom rules performed by the Sui Move bytecode verifier that ensures
 /// that `T` is an object defined in the module where `share_object` is invoked. Use
 /// `pub_share_object` to share an object with `store` outside of its module.
-pub fn share_object<T>(obj: T) {
+pub fn share_object<T: KeyObject + 'static>(obj: T) {
+    share_object_impl(obj)
 }
 
 /// Turn the given object into a mutable shared object that everyone can access and mutate.
@@ -110,7 +93,8 @@ pub fn share_object<T>(obj: T) {
 /// Aborts with `ESharedNonNewObject` of the object being shared was not created in this
 /// transaction. This restriction may be relaxed in the future.
 /// The object must have `store` to be shared outside of its module.
-pub fn pub_share_object<T>(obj: T) {
+pub fn pub_share_object<T: KeyObject + 'static>(obj: T) {
+    share_object_impl(obj)
 }
 
 /// Given mutable (i.e., locked) access to the `parent` and a `Receiving` argument
@@ -119,27 +103,166 @@ pub fn pub_share_object<T>(obj: T) {
 /// This fnction has custom rules performed by the Sui Move bytecode verifier that ensures
 /// that `T` is an object defined in the module where `receive` is invoked. Use
 /// `pub_receive` to receivne an object with `store` outside of its module.
-pub fn receive<T>(parent: &mut u8, to_receive: Receiving) {
-    let Receiving { id, version } = to_receive;
+pub fn receive<T: KeyObject + 'static>(parent: &mut UID, to_receive: Receiving<T>) -> T {
+    receive_impl(parent, to_receive)
 }
 
 /// Given mutable (i.e., locked) access to the `parent` and a `Receiving` argument
 /// referencing an object of type `T` owned by `parent` 
 /// argument to receive and return the referenced owned object of type `T`.
 /// The object must have `store` to be received outside of its defining module.
-pub fn pub_receive<T>(parent: &mut u8, to_receive: Receiving) {
-    let Receiving { id, version } = to_receive;
+pub fn pub_receive<T: KeyObject + 'static>(parent: &mut UID, to_receive: Receiving<T>) -> T {
+    receive_impl(parent, to_receive)
 }
 
-/// Return the object u8 that the given `Receiving` argument references.
-pub fn receiving_object_id<T>(receiving: &Receiving) -> u8 {
+/// Return the object ID that the given `Receiving` argument references.
+pub fn receiving_object_id<T>(receiving: &Receiving<T>) -> ID {
     receiving.id
 }
 
-pub fn make_receiver<T>(id: u8, version: u64) -> Receiving {
-    Receiving { id, version }
+pub fn make_receiver<T>(id: ID, version: u64) -> Receiving<T> {
+    Receiving { id, version, _object: PhantomData }
 }
 
-pub fn receiving_id<T>(r: &Receiving) -> u8 {
+pub fn receiving_id<T>(r: &Receiving<T>) -> ID {
     r.id
-}
\ No newline at end of file
+}
+
+fn freeze_object_impl<T: KeyObject + 'static>(obj: T) {
+    let id = object::id(&obj);
+    abort::check(
+        ledger::owner(id) != Some(Owner::Shared),
+        "transfer",
+        "freeze_object_impl",
+        ESharedObjectOperationNotSupported,
+    );
+    ledger::store(id, Owner::Immutable, Box::new(obj));
+}
+
+fn share_object_impl<T: KeyObject + 'static>(obj: T) {
+    let id = object::id(&obj);
+    match ledger::owner(id) {
+        // Shared objects taken out during the transaction are shared again.
+        Some(Owner::Shared) => {}
+        Some(Owner::Immutable) => {
+            abort::raise("transfer", "share_object_impl", ledger::EImmutableObjectOperationNotSupported)
+        }
+        _ => abort::check(object::is_new(&id), "transfer", "share_object_impl", ESharedNonNewObject),
+    }
+    ledger::store(id, Owner::Shared, Box::new(obj));
+}
+
+fn transfer_impl<T: KeyObject + 'static>(obj: T, recipient: Address) {
+    let id = object::id(&obj);
+    match ledger::owner(id) {
+        Some(Owner::Shared) => abort::raise("transfer", "transfer_impl", ESharedObjectOperationNotSupported),
+        Some(Owner::Immutable) => abort::raise("transfer", "transfer_impl", ledger::EImmutableObjectOperationNotSupported),
+        _ => {}
+    }
+    let parent = object::id_from_address(recipient);
+    // Sending to the address of a live object parks the object under it.
+    let owner = if object::is_live(&parent) { Owner::ObjectOwner(parent) } else { Owner::AddressOwner(recipient) };
+    if let (Some(coin), Owner::AddressOwner(recipient)) = (obj.coin_type(), &owner) {
+        abort::check(!deny::blocks_receipt(coin, *recipient), "transfer", "transfer_impl", deny::EAddressDeniedForCoin);
+    }
+    ledger::store(id, owner, Box::new(obj));
+}
+
+fn receive_impl<T: KeyObject + 'static>(parent: &mut UID, to_receive: Receiving<T>) -> T {
+    let Receiving { id, version, _object } = to_receive;
+    let parent = object::uid_to_inner(parent);
+    abort::check(
+        ledger::owner(id) == Some(Owner::ObjectOwner(parent))
+            && ledger::version(id) == Some(version)
+            && ledger::is_available(id),
+        "transfer",
+        "receive_impl",
+        EUnableToReceiveObject,
+    );
+    let obj = ledger::take(id).unwrap();
+    if !obj.is::<T>() {
+        ledger::put_back(id, obj);
+        abort::raise("transfer", "receive_impl", EReceivingObjectTypeMismatch);
+    }
+    ledger::remove(id);
+    *obj.downcast::<T>().unwrap()
+}
+
+// === verification ===
+
+/// Who owns the object `id` right now. Live objects that are not in storage on their own
+/// are `Wrapped`, deleted or unknown objects have no owner. Objects taken out of storage
+/// and not put back, transferred, shared or frozen are `Wrapped` too, as they will be once
+/// the transaction ends.
+pub fn owner(id: &ID) -> Option<Owner> {
+    match ledger::owner(*id) {
+        Some(_) if ledger::is_taken(*id) => Some(Owner::Wrapped),
+        Some(owner) => Some(owner),
+        None if object::is_live(id) => Some(Owner::Wrapped),
+        None => None,
+    }
+}
+
+/// ID of the last object of type `T` sent to `account` that is still there.
+pub fn most_recent_id_for_address<T: 'static>(account: &Address) -> Option<ID> {
+    ledger::most_recent::<T>(|owner| *owner == Owner::AddressOwner(*account))
+}
+
+/// ID of the last shared object of type `T`.
+pub fn most_recent_id_shared<T: 'static>() -> Option<ID> {
+    ledger::most_recent::<T>(|owner| *owner == Owner::Shared)
+}
+
+/// ID of the last frozen object of type `T`.
+pub fn most_recent_id_immutable<T: 'static>() -> Option<ID> {
+    ledger::most_recent::<T>(|owner| *owner == Owner::Immutable)
+}
+
+/// Ticket for the last object of type `T` sent to the object `parent`, at its current version.
+pub fn most_recent_receiving_ticket<T: 'static>(parent: &ID) -> Receiving<T> {
+    let id = ledger::most_recent::<T>(|owner| *owner == Owner::ObjectOwner(*parent))
+        .expect("no such object for parent");
+    make_receiver(id, ledger::version(id).unwrap())
+}
+
+/// Take the object `id` out of storage to use it in a transaction. Its owner stays recorded
+/// until it is transferred, shared, frozen or put back with `return_object`.
+/// Aborts if `id` is a coin its owner is denied from using.
+pub fn take_by_id<T: KeyObject + 'static>(id: ID) -> T {
+    let obj = ledger::take(id).expect("object is not available in storage");
+    let obj = match obj.downcast::<T>() {
+        Ok(obj) => *obj,
+        Err(obj) => {
+            ledger::put_back(id, obj);
+            panic!("object has a different type")
+        }
+    };
+    if let (Some(coin), Some(Owner::AddressOwner(account))) = (obj.coin_type(), ledger::owner(id)) {
+        if deny::blocks_input(coin, account) {
+            ledger::put_back(id, Box::new(obj));
+            abort::raise("transfer", "take_by_id", deny::EAddressDeniedForCoin);
+        }
+    }
+    obj
+}
+
+/// Take the last object of type `T` sent to `account`.
+pub fn take_from_address<T: KeyObject + 'static>(account: Address) -> T {
+    take_by_id(most_recent_id_for_address::<T>(&account).expect("no such object for address"))
+}
+
+/// Take the last shared object of type `T`.
+pub fn take_shared<T: KeyObject + 'static>() -> T {
+    take_by_id(most_recent_id_shared::<T>().expect("no such shared object"))
+}
+
+/// Take the last frozen object of type `T`.
+pub fn take_immutable<T: KeyObject + 'static>() -> T {
+    take_by_id(most_recent_id_immutable::<T>().expect("no such immutable object"))
+}
+
+/// Put an object taken out of storage back, with the same owner.
+pub fn return_object<T: KeyObject + 'static>(obj: T) {
+    let id = object::id(&obj);
+    ledger::put_back(id, Box::new(obj));
+}
@@ -0,0 +1 @@
+pub(crate) mod tx_context;
\ No newline at end of file
@@ -0,0 +1,168 @@
+use crate::runtime;
+use crate::runtime::{abort, arith};
+use crate::runtime::ids;
+use crate::sui_std::address::address::Address;
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+pub struct sui__tx_context {}
+
+/// Number of bytes in an tx hash (which will be the transaction digest)
+const TX_HASH_LENGTH: u64 = 32;
+
+/// Expected an tx hash of length 32, but found a different length
+pub const EBadTxHashLength: u64 = 0;
+
+/// Information about the transaction currently being executed.
+/// This cannot be constructed by a transaction--it is a privileged object created by
+/// the VM and passed in to the entrypoint of the transaction as `&mut TxContext`.
+pub struct TxContext {
+    /// The address of the user that signed the current transaction
+    sender: Address,
+    /// Hash of the current transaction
+    tx_hash: Vec<u8>,
+    /// The current epoch number
+    epoch: u64,
+    /// Timestamp that the epoch started at
+    epoch_timestamp_ms: u64,
+    /// Counter recording the number of fresh id's created while executing
+    /// this transaction. Always 0 at the start of a transaction
+    ids_created: u64,
+}
+
+impl TxContext {
+    /// Return the address of the user that signed the current
+    /// transaction
+    pub fn sender(self: &TxContext) -> Address {
+        sender(self)
+    }
+
+    /// Return the transaction digest (hash of transaction inputs).
+    pub fn digest(self: &TxContext) -> &Vec<u8> {
+        digest(self)
+    }
+
+    /// Return the current epoch
+    pub fn epoch(self: &TxContext) -> u64 {
+        epoch(self)
+    }
+
+    /// Return the epoch start time as a unix timestamp in milliseconds.
+    pub fn epoch_timestamp_ms(self: &TxContext) -> u64 {
+        epoch_timestamp_ms(self)
+    }
+
+    /// Create an `address` that has not been used.
+    pub fn fresh_object_address(self: &mut TxContext) -> Address {
+        fresh_object_address(self)
+    }
+}
+
+/// Return the address of the user that signed the current
+/// transaction
+pub fn sender(ctx: &TxContext) -> Address {
+    ctx.sender
+}
+
+/// Return the transaction digest (hash of transaction inputs).
+/// Please do not use as a source of randomness.
+pub fn digest(ctx: &TxContext) -> &Vec<u8> {
+    &ctx.tx_hash
+}
+
+/// Return the current epoch
+pub fn epoch(ctx: &TxContext) -> u64 {
+    ctx.epoch
+}
+
+/// Return the epoch start time as a unix timestamp in milliseconds.
+pub fn epoch_timestamp_ms(ctx: &TxContext) -> u64 {
+    ctx.epoch_timestamp_ms
+}
+
+/// Create an `address` that has not been used. As it is an object address, it will never
+/// occur as the address for a user.
+/// In other words, the generated address is a globally unique object ID.
+pub fn fresh_object_address(ctx: &mut TxContext) -> Address {
+    ctx.ids_created = arith::add(ctx.ids_created, 1);
+    Address::from_u128(ids::fresh_id() as u128)
+}
+
+/// Return the number of id's created by the current transaction.
+/// Hidden for now, but may expose later
+fn ids_created(ctx: &TxContext) -> u64 {
+    ctx.ids_created
+}
+
+// === testing and verification ===
+
+/// Create a `TxContext` for testing. Each context starts a new transaction.
+pub fn new(
+    sender: Address,
+    tx_hash: Vec<u8>,
+    epoch: u64,
+    epoch_timestamp_ms: u64,
+    ids_created: u64,
+) -> TxContext {
+    abort::check(tx_hash.len() as u64 == TX_HASH_LENGTH, "tx_context", "new", EBadTxHashLength);
+    runtime::begin_transaction(epoch);
+    TxContext { sender, tx_hash, epoch, epoch_timestamp_ms, ids_created }
+}
+
+/// Create a `TxContext` for testing, with a potentially non-zero epoch number.
+pub fn new_from_hint(
+    sender: Address,
+    hint: u64,
+    epoch: u64,
+    epoch_timestamp_ms: u64,
+    ids_created: u64,
+) -> TxContext {
+    new(sender, dummy_tx_hash_with_hint(hint), epoch, epoch_timestamp_ms, ids_created)
+}
+
+/// Create a dummy `TxContext` for testing
+pub fn dummy() -> TxContext {
+    let tx_hash = vec![0u8; TX_HASH_LENGTH as usize];
+    new(Address::from_u128(0x0), tx_hash, 0, 0, 0)
+}
+
+/// Utility for creating 256 unique input hashes.
+/// These hashes are guaranteed to be unique given a unique `hint: u64`
+fn dummy_tx_hash_with_hint(hint: u64) -> Vec<u8> {
+    let mut tx_hash = hint.to_le_bytes().to_vec();
+    tx_hash.resize(TX_HASH_LENGTH as usize, 0);
+    tx_hash
+}
+
+pub fn get_ids_created(ctx: &TxContext) -> u64 {
+    ids_created(ctx)
+}
+
+/// The rest of the transaction runs in the next epoch, e.g. to see deny list
+/// changes take effect.
+pub fn increment_epoch_number(ctx: &mut TxContext) {
+    ctx.epoch = arith::add(ctx.epoch, 1);
+    runtime::deny::set_epoch(ctx.epoch)
+}
+
+pub fn increment_epoch_timestamp(ctx: &mut TxContext, delta_ms: u64) {
+    ctx.epoch_timestamp_ms = arith::add(ctx.epoch_timestamp_ms, delta_ms)
+}
+
+/// A context whose sender is one of `address::SYMBOLIC_ADDRESSES` and whose
+/// epoch and epoch start are unconstrained.
+#[cfg(kani)]
+impl kani::Arbitrary for TxContext {
+    fn any() -> Self {
+        let epoch = kani::any();
+        runtime::begin_transaction(epoch);
+        TxContext {
+            sender: kani::any(),
+            tx_hash: vec![0u8; TX_HASH_LENGTH as usize],
+            epoch,
+            epoch_timestamp_ms: kani::any(),
+            ids_created: 0,
+        }
+    }
+}
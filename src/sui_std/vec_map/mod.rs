@@ -0,0 +1 @@
+pub(crate) mod vec_map;
\ No newline at end of file
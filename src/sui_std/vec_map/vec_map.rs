@@ -0,0 +1,174 @@
+use crate::runtime::abort;
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+pub struct sui__vec_map {}
+
+/// This key already exists in the map
+pub const EKeyAlreadyExists: u64 = 0;
+/// This key does not exist in the map
+pub const EKeyDoesNotExist: u64 = 1;
+/// Trying to destroy a map that is not empty
+pub const ECannotDestroyNonEmpty: u64 = 2;
+/// Trying to access an element of the map at an invalid index
+pub const EIndexOutOfBounds: u64 = 3;
+/// Trying to pop from a map that is empty
+pub const EMapEmpty: u64 = 4;
+/// Must construct a VecMap of equal size
+pub const EUnequalLengths: u64 = 5;
+
+/// A map data structure backed by a vector. The map is guaranteed not to contain duplicate keys, but entries
+/// are *not* sorted by key--entries are included in insertion order.
+/// All operations are O(N) in the size of the map--the intention of this data structure is only to provide
+/// the convenience of programming against a map API.
+/// Large maps should use handwritten parent/child relationships instead.
+/// Maps that need sorted iteration rather than insertion order iteration should also be handwritten.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VecMap<K, V> {
+    contents: Vec<Entry<K, V>>,
+}
+
+/// An entry in the map
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry<K, V> {
+    key: K,
+    value: V,
+}
+
+/// Create an empty `VecMap`
+pub fn empty<K, V>() -> VecMap<K, V> {
+    VecMap { contents: Vec::new() }
+}
+
+/// Insert the entry `key` |-> `value` into `self`.
+/// Aborts if `key` is already bound in `self`.
+pub fn insert<K: PartialEq, V>(map: &mut VecMap<K, V>, key: K, value: V) {
+    abort::check(!contains(map, &key), "vec_map", "insert", EKeyAlreadyExists);
+    map.contents.push(Entry { key, value })
+}
+
+/// Remove the entry `key` |-> `value` from self. Aborts if `key` is not bound in `self`.
+pub fn remove<K: PartialEq, V>(map: &mut VecMap<K, V>, key: &K) -> (K, V) {
+    let idx = get_idx(map, key);
+    let Entry { key, value } = map.contents.remove(idx as usize);
+    (key, value)
+}
+
+/// Pop the most recently inserted entry from the map. Aborts if the map is empty.
+pub fn pop<K, V>(map: &mut VecMap<K, V>) -> (K, V) {
+    abort::check(!map.contents.is_empty(), "vec_map", "pop", EMapEmpty);
+    let Entry { key, value } = map.contents.pop().unwrap();
+    (key, value)
+}
+
+/// Get a mutable reference to the value bound to `key` in `self`.
+/// Aborts if `key` is not bound in `self`.
+pub fn get_mut<'a, K: PartialEq, V>(map: &'a mut VecMap<K, V>, key: &K) -> &'a mut V {
+    let idx = get_idx(map, key);
+    &mut map.contents[idx as usize].value
+}
+
+/// Get a reference to the value bound to `key` in `self`.
+/// Aborts if `key` is not bound in `self`.
+pub fn get<'a, K: PartialEq, V>(map: &'a VecMap<K, V>, key: &K) -> &'a V {
+    let idx = get_idx(map, key);
+    &map.contents[idx as usize].value
+}
+
+/// Safely try borrow a value bound to `key` in `self`.
+/// Return Some(V) if the value exists, None otherwise.
+/// Only works for a "copyable" value as references cannot be stored in `vector`.
+pub fn try_get<K: PartialEq, V: Clone>(map: &VecMap<K, V>, key: &K) -> Option<V> {
+    if contains(map, key) {
+        Some(get(map, key).clone())
+    } else {
+        None
+    }
+}
+
+/// Return true if `self` contains an entry for `key`, false otherwise
+pub fn contains<K: PartialEq, V>(map: &VecMap<K, V>, key: &K) -> bool {
+    get_idx_opt(map, key).is_some()
+}
+
+/// Return the number of entries in `self`
+pub fn size<K, V>(map: &VecMap<K, V>) -> u64 {
+    map.contents.len() as u64
+}
+
+/// Return true if `self` has 0 elements, false otherwise
+pub fn is_empty<K, V>(map: &VecMap<K, V>) -> bool {
+    size(map) == 0
+}
+
+/// Destroy an empty map. Aborts if `self` is not empty
+pub fn destroy_empty<K, V>(map: VecMap<K, V>) {
+    abort::check(is_empty(&map), "vec_map", "destroy_empty", ECannotDestroyNonEmpty);
+}
+
+/// Unpack `self` into vectors of its keys and values.
+/// The output keys and values are stored in insertion order, *not* sorted by key.
+pub fn into_keys_values<K, V>(map: VecMap<K, V>) -> (Vec<K>, Vec<V>) {
+    map.contents.into_iter().map(|Entry { key, value }| (key, value)).unzip()
+}
+
+/// Construct a new `VecMap` from two vectors, one for keys and one for values.
+/// The key value pairs are associated via their indices in the vectors, e.g. the key at index i
+/// in `keys` is associated with the value at index i in `values`.
+/// The key value pairs are stored in insertion order (the original vectors ordering)
+/// and are *not* sorted.
+pub fn from_keys_values<K: PartialEq, V>(keys: Vec<K>, values: Vec<V>) -> VecMap<K, V> {
+    abort::check(keys.len() == values.len(), "vec_map", "from_keys_values", EUnequalLengths);
+    let mut map = empty();
+    for (key, value) in keys.into_iter().zip(values) {
+        insert(&mut map, key, value);
+    }
+    map
+}
+
+/// Returns a list of keys in the map.
+/// Do not assume any particular ordering.
+pub fn keys<K: Clone, V>(map: &VecMap<K, V>) -> Vec<K> {
+    map.contents.iter().map(|entry| entry.key.clone()).collect()
+}
+
+/// Find the index of `key` in `self`. Return `None` if `key` is not in `self`.
+/// Note that map entries are stored in insertion order, *not* sorted by key.
+pub fn get_idx_opt<K: PartialEq, V>(map: &VecMap<K, V>, key: &K) -> Option<u64> {
+    map.contents.iter().position(|entry| entry.key == *key).map(|i| i as u64)
+}
+
+/// Find the index of `key` in `self`. Aborts if `key` is not in `self`.
+/// Note that map entries are stored in insertion order, *not* sorted by key.
+pub fn get_idx<K: PartialEq, V>(map: &VecMap<K, V>, key: &K) -> u64 {
+    let idx_opt = get_idx_opt(map, key);
+    abort::check(idx_opt.is_some(), "vec_map", "get_idx", EKeyDoesNotExist);
+    idx_opt.unwrap()
+}
+
+/// Return a reference to the `idx`th entry of `self`. This gives direct access into the backing array of the map--use with caution.
+/// Note that map entries are stored in insertion order, *not* sorted by key.
+/// Aborts if `idx` is greater than or equal to `size(self)`
+pub fn get_entry_by_idx<K, V>(map: &VecMap<K, V>, idx: u64) -> (&K, &V) {
+    abort::check(idx < size(map), "vec_map", "get_entry_by_idx", EIndexOutOfBounds);
+    let entry = &map.contents[idx as usize];
+    (&entry.key, &entry.value)
+}
+
+/// Return a mutable reference to the `idx`th entry of `self`. This gives direct access into the backing array of the map--use with caution.
+/// Note that map entries are stored in insertion order, *not* sorted by key.
+/// Aborts if `idx` is greater than or equal to `size(self)`
+pub fn get_entry_by_idx_mut<K, V>(map: &mut VecMap<K, V>, idx: u64) -> (&K, &mut V) {
+    abort::check(idx < size(map), "vec_map", "get_entry_by_idx_mut", EIndexOutOfBounds);
+    let entry = &mut map.contents[idx as usize];
+    (&entry.key, &mut entry.value)
+}
+
+/// Remove the entry at index `idx` from self.
+/// Aborts if `idx` is greater than or equal to `size(self)`
+pub fn remove_entry_by_idx<K, V>(map: &mut VecMap<K, V>, idx: u64) -> (K, V) {
+    abort::check(idx < size(map), "vec_map", "remove_entry_by_idx", EIndexOutOfBounds);
+    let Entry { key, value } = map.contents.remove(idx as usize);
+    (key, value)
+}
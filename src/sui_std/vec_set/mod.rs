@@ -0,0 +1 @@
+pub(crate) mod vec_set;
\ No newline at end of file
@@ -0,0 +1,121 @@
+use crate::runtime::abort;
+
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+pub struct sui__vec_set {}
+
+/// This key already exists in the map
+pub const EKeyAlreadyExists: u64 = 0;
+/// This key does not exist in the map
+pub const EKeyDoesNotExist: u64 = 1;
+
+/// Most elements an arbitrary `VecSet` has, so loops over it stay within kani's unwinding.
+#[cfg(kani)]
+pub const MAX_ARBITRARY_SIZE: u64 = 3;
+
+/// A set data structure backed by a vector. The set is guaranteed not to
+/// contain duplicate keys. All operations are O(N) in the size of the set
+/// - the intention of this data structure is only to provide the convenience
+/// of programming against a set API. Sets that need sorted iteration rather
+/// than insertion order iteration should be handwritten.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VecSet<K> {
+    contents: Vec<K>,
+}
+
+/// Create an empty `VecSet`
+pub fn empty<K>() -> VecSet<K> {
+    VecSet { contents: Vec::new() }
+}
+
+/// Create a singleton `VecSet` that only contains one element.
+pub fn singleton<K>(key: K) -> VecSet<K> {
+    VecSet { contents: vec![key] }
+}
+
+/// Insert a `key` into self.
+/// Aborts if `key` is already present in `self`.
+pub fn insert<K: PartialEq>(set: &mut VecSet<K>, key: K) {
+    abort::check(!contains(set, &key), "vec_set", "insert", EKeyAlreadyExists);
+    set.contents.push(key)
+}
+
+/// Remove the entry `key` from self. Aborts if `key` is not present in `self`.
+pub fn remove<K: PartialEq>(set: &mut VecSet<K>, key: &K) {
+    let idx = get_idx(set, key);
+    set.contents.remove(idx as usize);
+}
+
+/// Return true if `self` contains an entry for `key`, false otherwise
+pub fn contains<K: PartialEq>(set: &VecSet<K>, key: &K) -> bool {
+    get_idx_opt(set, key).is_some()
+}
+
+/// Return the number of entries in `self`
+pub fn size<K>(set: &VecSet<K>) -> u64 {
+    set.contents.len() as u64
+}
+
+/// Return true if `self` has 0 elements, false otherwise
+pub fn is_empty<K>(set: &VecSet<K>) -> bool {
+    size(set) == 0
+}
+
+/// Unpack `self` into vectors of keys.
+/// The output keys are stored in insertion order, *not* sorted.
+pub fn into_keys<K>(set: VecSet<K>) -> Vec<K> {
+    set.contents
+}
+
+/// Construct a new `VecSet` from a vector of keys.
+/// The keys are stored in insertion order (the original `keys` ordering)
+/// and are *not* sorted.
+pub fn from_keys<K: PartialEq>(keys: Vec<K>) -> VecSet<K> {
+    let mut set = empty();
+    for key in keys {
+        insert(&mut set, key);
+    }
+    set
+}
+
+/// Borrow the `contents` of the `VecSet` to access content by index
+/// without unpacking. The contents are stored in insertion order,
+/// *not* sorted.
+pub fn keys<K>(set: &VecSet<K>) -> &Vec<K> {
+    &set.contents
+}
+
+// === Internal functions ===
+
+/// Find the index of `key` in `self`. Return `None` if `key` is not in `self`.
+/// Note that keys are stored in insertion order, *not* sorted.
+fn get_idx_opt<K: PartialEq>(set: &VecSet<K>, key: &K) -> Option<u64> {
+    set.contents.iter().position(|k| k == key).map(|i| i as u64)
+}
+
+/// Find the index of `key` in `self`. Aborts if `key` is not in `self`.
+/// Note that map entries are stored in insertion order, *not* sorted.
+fn get_idx<K: PartialEq>(set: &VecSet<K>, key: &K) -> u64 {
+    let idx_opt = get_idx_opt(set, key);
+    abort::check(idx_opt.is_some(), "vec_set", "get_idx", EKeyDoesNotExist);
+    idx_opt.unwrap()
+}
+
+/// Up to `MAX_ARBITRARY_SIZE` distinct arbitrary keys. A key drawn twice is
+/// kept once rather than assumed away, so small key types such as the
+/// symbolic addresses never make the generator vacuous.
+#[cfg(kani)]
+impl<K: kani::Arbitrary + PartialEq> kani::Arbitrary for VecSet<K> {
+    fn any() -> Self {
+        let size: u64 = kani::any_where(|n: &u64| *n <= MAX_ARBITRARY_SIZE);
+        let mut set = empty();
+        for _ in 0..size {
+            let key: K = kani::any();
+            if !contains(&set, &key) {
+                set.contents.push(key);
+            }
+        }
+        set
+    }
+}
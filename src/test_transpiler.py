@@ -0,0 +1,122 @@
+import unittest
+
+from transpiler import checked_arithmetic, move_to_rust, raise_move_aborts
+
+
+def arith(line):
+    """The rewritten line, without the `use` that `checked_arithmetic` adds."""
+    return checked_arithmetic(line).splitlines()[-1]
+
+
+class CheckedArithmeticTest(unittest.TestCase):
+    def test_precedence_and_associativity(self):
+        self.assertEqual(arith("a + b * c"), "arith::add(a, arith::mul(b, c))")
+        self.assertEqual(arith("a - b - c"), "arith::sub(arith::sub(a, b), c)")
+        self.assertEqual(arith("x << a + 1"), "arith::shl(x, arith::add(a, 1))")
+
+    def test_parenthesized_operands(self):
+        self.assertEqual(arith("(a + b) * c"), "arith::mul(arith::add(a, b), c)")
+        self.assertEqual(arith("a * (b - c)"), "arith::mul(a, arith::sub(b, c))")
+        self.assertEqual(arith("((a + b) * c) / d"), "arith::div(arith::mul(arith::add(a, b), c), d)")
+        self.assertEqual(arith("if (a + b > c) {"), "if (arith::add(a, b) > c) {")
+
+    def test_dereferenced_and_borrowed_operands(self):
+        self.assertEqual(arith("*x + 1"), "arith::add(*x, 1)")
+        self.assertEqual(arith("*counter = *counter + 1;"), "*counter = arith::add(*counter, 1);")
+        self.assertEqual(arith("a * *b"), "arith::mul(a, *b)")
+        self.assertEqual(arith("&x.y + 1"), "arith::add(&x.y, 1)")
+
+    def test_indexed_operands(self):
+        self.assertEqual(arith("v[i] + 1"), "arith::add(v[i], 1)")
+        self.assertEqual(arith("v[i + 1] * 2"), "arith::mul(v[arith::add(i, 1)], 2)")
+
+    def test_calls(self):
+        self.assertEqual(arith("f(a + b, c) / d"), "arith::div(f(arith::add(a, b), c), d)")
+        self.assertEqual(arith("x.f(y).g + 1"), "arith::add(x.f(y).g, 1)")
+        self.assertEqual(arith("arith::cast::<_, u8>(x) + 1"), "arith::add(arith::cast::<_, u8>(x), 1)")
+        self.assertEqual(arith("assert!(a + b > c)"), "assert!(arith::add(a, b) > c)")
+
+    def test_untouched(self):
+        self.assertEqual(checked_arithmetic("(*x).f = y;"), "(*x).f = y;")
+        self.assertEqual(checked_arithmetic("let t: Table<K, Vec<u8>> = x;"), "let t: Table<K, Vec<u8>> = x;")
+        self.assertEqual(checked_arithmetic("    // a + b"), "    // a + b")
+
+    def test_long_lines(self):
+        # No catastrophic backtracking on long operands that are not followed by an operator.
+        line = "let x = " + "a.b." * 200 + "c;"
+        self.assertEqual(checked_arithmetic(line), line)
+
+
+def aborts(line):
+    """`line` as rewritten inside function `f` of module `m`."""
+    return raise_move_aborts(f"pub struct a__m {{}}\nimpl a__m {{\n    fn f() {{\n{line}\n    }}\n}}").splitlines()[-3]
+
+
+class RaiseMoveAbortsTest(unittest.TestCase):
+    def test_asserts_with_a_code(self):
+        self.assertEqual(aborts("assert!(a > b, EFoo);"), 'abort::check(a > b, "m", "f", EFoo);')
+        self.assertEqual(aborts("assert!(f(a, b), EFoo);"), 'abort::check(f(a, b), "m", "f", EFoo);')
+        self.assertEqual(aborts("assert!(v[i] == (x, y), errors::foo());"), 'abort::check(v[i] == (x, y), "m", "f", errors::foo());')
+        self.assertEqual(aborts("assert!(a, EA); assert!(b, EB);"), 'abort::check(a, "m", "f", EA); abort::check(b, "m", "f", EB);')
+
+    def test_asserts_without_a_code(self):
+        self.assertEqual(aborts("assert!(f(a, b));"), "assert!(f(a, b));")
+        self.assertNotIn("use crate::runtime::abort;", raise_move_aborts("assert!(f(a, b));"))
+
+    def test_option_aborts(self):
+        rust = raise_move_aborts("option::fill(&mut x.y, z);")
+        self.assertTrue(rust.startswith("use crate::runtime::abort;"))
+        self.assertIn('abort::check(x.y.is_none(), "option", "fill", option::EOPTION_IS_SET); x.y = Some(z);', rust)
+
+
+class AddressTypeTest(unittest.TestCase):
+    MODULE = """module m::m {
+    use sui::address;
+
+    public struct Entry has store {
+        address: address,
+        owners: Table<u64, address>,
+        refs: vector<address>,
+    }
+
+    public fun get(e: &Entry, address: &mut address): (address, u256) {
+        let a: address = e.address;
+        (a, address::to_u256(*address))
+    }
+}"""
+
+    def test_only_types_become_address(self):
+        rust = move_to_rust(self.MODULE)
+        self.assertIn("    address: Address,", rust)
+        self.assertIn("    owners: Table<u64, Address>,", rust)
+        self.assertIn("    refs: Vec<Address>,", rust)
+        self.assertIn("fn get(e: &Entry, address: &mut Address): (Address, u256) {", rust)
+        self.assertIn("let a: Address = e.address;", rust)
+        self.assertIn("(a, address::to_u256(*address))", rust)
+
+
+class ImplicitImportsTest(unittest.TestCase):
+    MODULE = """module m::m {
+    public struct Thing has key {
+        id: UID,
+    }
+
+    fun init(ctx: &mut TxContext) {
+        transfer::share_object(Thing { id: object::new(ctx) });
+    }
+}"""
+
+    def test_modules_without_use_lines_import_what_they_use(self):
+        uses = [l for l in move_to_rust(self.MODULE).splitlines() if l.startswith("use ")]
+        self.assertIn("use crate::sui_std::object::object::{self, KeyObject, UID};", uses)
+        self.assertIn("use crate::sui_std::transfer::transfer;", uses)
+        self.assertIn("use crate::sui_std::tx_context::tx_context::TxContext;", uses)
+
+    def test_explicit_use_lines_do_not_import_unused_items(self):
+        rust = move_to_rust(self.MODULE.replace("{\n    public", "{\n    use sui::object::{Self, ID, UID};\n    public", 1))
+        self.assertIn("use crate::sui_std::object::object::{self, KeyObject, UID};", rust.splitlines())
+        self.assertEqual(rust.count("use crate::sui_std::object::object"), 1)
+
+
+if __name__ == "__main__":
+    unittest.main()
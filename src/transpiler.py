@@ -12,36 +12,24 @@ def move_to_rust(move_code):
         (r'\(package\)', r''), # Remove 'package' scope
         (r'entry fun', r'fun'), # Remove 'Entry'
         (r'fun', r'fn'), # Fun to fn
-        (r'\+\+', r'+=1'), # ++ not in rust syntaxis
+        (r'([\w.]+)\+\+', r'\1 = arith::add(\1, 1)'), # ++ not in rust syntaxis, and must abort on overflow
         (r'ascii', r'string'), # No need for ascii
         (r'string::String', r'String'), # Rename of string type
         (r'option::is_some\(&(\w+\.\w+)\)', r'\1.is_some()'), # Option is_some
         (r'option::is_none\(&(\w+\.\w+)\)', r'\1.is_none()'), # Option is_none
-        (r'option::fill\(&mut (\w+\.\w+), (\w+)\)', r'assert!(\1.replace(\2).is_none())'), # Option fill (assignment if is None, otherwise fail)
-        (r'option::extract\(&mut (\w+\.\w+)\)', r'\1.take().unwrap()'), # Option extract (take)
         (r'option::none\(\)', r'None'), # Option None
         (r'option::some\(\)', r'Some'), # Option Some
-        (r'assert!\((.+?),\s*(.+?)\)', r'assert!(\1, "{}", \2)'), # Assert with string literal
-        (r'ctx: &mut TxContext(,?)', r''), # Remove TxContxt. TODO: Might need to model this.
         (r'phantom ', r''), # Remove phantom
-        (r'Balance<[^>]+>', r'Balance'), # Balance type not parametric.
-        (r'Coin<[^>]+>', r'Coin'), # Coin type not parametric.
-        (r'Supply<[^>]+>', r'Supply'), # Supply type not parametric.
-        (r'TreasuryCap<[^>]+>', r'TreasuryCap'), # TreasuryCap type not parametric.
-        (r'CoinMetadata<[^>]+>', r'CoinMetadata'), # CoinMetadata type not parametric.
         (r'Url', r'String'), # Use strings for URLs.
-        (r'UID', r'ID'), # Transform all to ID an then...
-        (r'ID', r'u8'), # Use u8 for ID.
-        (r'address', r'String'), # Use string for address type.
+        (r'(?:(?<=[^:]:)|(?<=->)|(?<=<))(\s*(?:&(?:mut )?)?)address\b(?!::)', r'\1Address'), # address type, after `:`, `->` or `<`
+        (r'(?<=,)(\s*)address\b(?!::)(?=[^()<>]*>)', r'\1Address'), # address type argument after the first, e.g. Table<u64, address>
+        (r'(?<=[^:]:)( ?\([^()]*\))', lambda m: re.sub(r'\baddress\b(?!::)', 'Address', m.group(1))), # address in a tuple type, e.g. (address, u64)
         (r'vector<([^>]+)>', r'Vec<\1>'), # Rename to rust vector type.
         (r'return (.+?)(;)?', r'\1'), # Return in rust
-        (r'transfer::share_object\((.+?)\)(;)?', r'\1'), # Instead of move transfer, return
         (r'fn init', r'pub fn init'), # Set init as public
-        (r'VecMap', r'Map'), # Move map
     ]
 
     simplification_replacements = [
-        (r'UID', r'u8'), # Assume UID is u8
         (r'// === Tests ===', ''), # Common comment, tests are removed so this is also
         (r'#\[test_only\].*\n', ''),  # Remove test only imports
     ]
@@ -54,12 +42,17 @@ def move_to_rust(move_code):
     func_replacements = [
         remove_test_functions,
         return_type_from_colon_to_arrow,
-        add_new_object_mock,
         move_structs_and_consts_to_global_scope,
+        raise_move_aborts,
+        address_literals,
+        u256_literals,
+        checked_casts,
+        checked_arithmetic,
         remove_duplicate_line_breaks,
-        use_std_libs,
     ]
     
+    key_structs = find_key_structs(move_code)
+
     rust_code = move_code
     # Apply replacements
     for pattern, replacement in regex_replacements:
@@ -70,6 +63,9 @@ def move_to_rust(move_code):
     
     for replace_func in func_replacements:
         rust_code = replace_func(rust_code)
+
+    rust_code = implement_key_objects(rust_code, key_structs)
+    rust_code = use_std_libs(rust_code)
     
     return rust_code
 
@@ -115,6 +111,7 @@ def move_structs_and_consts_to_global_scope(code):
             elif lines[i].strip().startswith("const"):
                 const_line = [lines[i]]
                 const_line = _remove_indentation_from_lines(const_line)
+                const_line = ["pub " + l for l in const_line] # Harnesses name the abort codes they expect
                 result_lines = const_line + ["\n"] + result_lines
                 i += 1
                 continue
@@ -173,37 +170,170 @@ def return_type_from_colon_to_arrow(code):
     
     return "\n".join(lines)
 
-def add_new_object_mock(code):
-    """Replaces calls to object::new(ctx) which assigns a specific UID in the blockchain
-    with calls to a local counter."""
-    id_getter_code = '''
-use std::sync::LazyLock;
+# `option::fill` and `option::extract` abort in the option module when the option is not as expected.
+_OPTION_ABORTS = [
+    (r'option::fill\(&mut (\w+\.\w+), (\w+)\)', r'abort::check(\1.is_none(), "option", "fill", option::EOPTION_IS_SET); \1 = Some(\2)'), # Option fill (assignment if is None, otherwise abort)
+    (r'option::extract\(&mut (\w+\.\w+)\)', r'{ abort::check(\1.is_some(), "option", "extract", option::EOPTION_NOT_SET); \1.take().unwrap() }'), # Option extract (take if is Some, otherwise abort)
+]
+
+def _check_asserts(line, module, function):
+    """`assert!(cond, CODE)` becomes `abort::check(cond, ...)`. The code follows the last comma outside
+    brackets; asserts without a code, or not closed on the same line, are left as they are."""
+    parts, pos = [], 0
+    for m in re.finditer(r'\bassert!\(', line):
+        if m.start() < pos:
+            continue
+        depth, comma, end = 0, None, None
+        for j in range(m.end(), len(line)):
+            if line[j] in "([{":
+                depth += 1
+            elif line[j] in ")]}":
+                if depth == 0:
+                    end = j
+                    break
+                depth -= 1
+            elif line[j] == "," and depth == 0:
+                comma = j
+        if end is None or comma is None:
+            continue
+        cond, abort_code = line[m.end():comma].strip(), line[comma + 1:end].strip()
+        parts += [line[pos:m.start()], f'abort::check({cond}, "{module}", "{function}", {abort_code})']
+        pos = end + 1
+    return "".join(parts) + line[pos:]
+
+def raise_move_aborts(code):
+    """`assert!(cond, CODE)` and `abort CODE` raise a `MoveAbort` naming the module and function they are in."""
+    module = re.search(r'pub struct \w+?__(\w+) \{\}\nimpl', code)
+    module = module.group(1) if module else ""
+    lines = code.splitlines()
+    function = ""
+    raised = False
 
-pub struct IdGetter {
-    current_id: std::sync::Mutex<u8>,
-}
+    for i in range(len(lines)):
+        if lines[i].strip().startswith("//"):
+            continue
+        fn_name = re.search(r'\bfn (\w+)', lines[i])
+        if fn_name and ("//" not in lines[i] or lines[i].index("fn") < lines[i].index("//")):
+            function = fn_name.group(1)
+        new_line = _check_asserts(lines[i], module, function)
+        for pattern, replacement in _OPTION_ABORTS:
+            new_line = re.sub(pattern, replacement, new_line)
+        new_line = re.sub(r'\babort (\w+)', rf'abort::raise("{module}", "{function}", \1)', new_line)
+        raised = raised or new_line != lines[i]
+        lines[i] = new_line
+
+    if raised:
+        lines = ["use crate::runtime::abort;"] + lines
+    return "\n".join(lines)
 
-impl IdGetter {
-    pub fn new() -> Self {
-        IdGetter {
-            current_id: std::sync::Mutex::new(0),
-        }
-    }
-
-    pub fn get_new_id(&self) -> u8 {
-        let mut id = self.current_id.lock().unwrap();
-        *id += 1;
-        *id
-    }
-}
+def address_literals(code):
+    """`@0x1` becomes `Address::from_u128(0x1)`, and literals past u128 are split into halves."""
+    def literal(m):
+        value = int(m.group(1), 16)
+        if value < 2**128:
+            return f'Address::from_u128({m.group(1)})'
+        return f'Address::from_limbs({hex(value >> 128)}, {hex(value & (2**128 - 1))})'
+
+    return re.sub(r'@(0x[0-9a-fA-F]+)', literal, code)
+
+def u256_literals(code):
+    """`5u256` becomes `U256::from_u128(5)`, and literals past u128 are split into halves."""
+    def literal(m):
+        value = int(m.group(1).replace('_', ''), 0)
+        if value < 2**128:
+            return f'U256::from_u128({value})'
+        return f'U256::from_limbs({value >> 128}, {value & (2**128 - 1)})'
+
+    code = re.sub(r'\b(0x[0-9a-fA-F_]+|[0-9][0-9_]*)u256\b', literal, code)
+    if re.search(r'\b(u256|U256)\b', code):
+        code = "use crate::runtime::u256::{u256, U256};\n" + code
+    return code
 
-// Use LazyLock to initialize ID_GETTER
-pub static ID_GETTER: LazyLock<IdGetter> = LazyLock::new(|| IdGetter::new());
+def checked_casts(code):
+    """`(e as u8)` becomes `arith::cast::<_, u8>(e)`, which aborts when `e` does not fit, as in Move."""
+    return re.sub(
+        r'\(((?:[^()]|\([^()]*\))+?) as (u8|u16|u32|u64|u128|u256)\)',
+        r'arith::cast::<_, \2>(\1)',
+        code,
+    )
+
+# Innermost group: parentheses or brackets with no other group inside
+_GROUP = r'\([^()\[\]]*\)|\[[^()\[\]]*\]'
+# Operands the arithmetic rewrite understands: paths, field accesses and literals, possibly dereferenced or
+# borrowed, followed by turbofish, calls and indexing. Groups are rewritten first and stand in as `\0n\0`.
+_OPERAND = r'[*&]*(?:mut )?(?:[\w.:]|(?<=::)<[^<>()]*>|\x00\d+\x00)+'
+# Move precedence, tightest first
+_ARITHMETIC_OPS = [
+    {'*': 'mul', '/': 'div', '%': 'rem'},
+    {'+': 'add', '-': 'sub'},
+    {'<<': 'shl', '>>': 'shr'},
+]
+
+def checked_arithmetic(code):
+    """`a + b` becomes `arith::add(a, b)` and so on, so overflow and division by zero abort as in Move."""
+    lines = code.splitlines()
+    rewritten = False
 
-'''
-    code = id_getter_code + code
-    code = re.sub(r'object::new\(\w*\)', 'ID_GETTER.get_new_id()', code, flags=re.MULTILINE)
-    return code
+    for i in range(len(lines)):
+        if lines[i].strip().startswith("//"):
+            continue
+        line, comment = lines[i], ""
+        if "//" in line:
+            line, comment = line[:line.index("//")], line[line.index("//"):]
+        groups = []
+
+        def hide(group):
+            groups.append(group)
+            return f'\x00{len(groups) - 1}\x00'
+
+        def rewrite(expr):
+            for ops in _ARITHMETIC_OPS:
+                pattern = rf'({_OPERAND}) ({"|".join(re.escape(op) for op in ops)}) ({_OPERAND})'
+                previous = None
+                while previous != expr:
+                    previous = expr
+                    expr = re.sub(pattern, lambda m: f'arith::{ops[m.group(2)]}' + hide(f'({m.group(1)}, {m.group(3)})'), expr, count=1)
+            return expr
+
+        def innermost(m):
+            group = m.group(0)
+            inner = rewrite(group[1:-1])
+            is_call = m.start() > 0 and re.match(r'[\w\x00>\])!]', m.string[m.start() - 1])
+            # `(a + b) * c`: the parentheses only grouped what is now a single call
+            if group[0] == '(' and not is_call and inner != group[1:-1] and re.fullmatch(_OPERAND, inner):
+                return inner
+            return hide(group[0] + inner + group[-1])
+
+        previous = None
+        while previous != line:
+            previous = line
+            line = re.sub(_GROUP, innermost, line)
+        line = rewrite(line)
+        while '\x00' in line:
+            line = re.sub(r'\x00(\d+)\x00', lambda m: groups[int(m.group(1))], line)
+
+        rewritten = rewritten or line + comment != lines[i]
+        lines[i] = line + comment
+
+    if rewritten or "arith::" in code:
+        lines = ["use crate::runtime::arith;"] + lines
+    return "\n".join(lines)
+
+def find_key_structs(move_code):
+    """Names and type parameters of the structs declared with the `key` ability, before abilities are removed."""
+    structs = re.findall(r'struct\s+(\w+)(?:<([^>]+)>)?\s+has\s+[\w\s,]*\bkey\b', move_code)
+    return [
+        (name, [re.sub(r'phantom\s+|:.*', '', p).strip() for p in params.split(',')] if params else [])
+        for name, params in structs
+    ]
+
+def implement_key_objects(code, key_structs):
+    """Structs with `key` get a `KeyObject` impl, so object::id and object::borrow_id work on them."""
+    impls = []
+    for name, params in key_structs:
+        generics = f"<{', '.join(params)}>" if params else ""
+        impls.append(f"impl{generics} KeyObject for {name}{generics} {{\n    fn uid(&self) -> &UID {{ &self.id }}\n}}\n")
+    return code + "\n\n" + "\n".join(impls) if impls else code
 
 def remove_duplicate_line_breaks(code):
     lines = code.splitlines()
@@ -215,6 +345,14 @@ def remove_duplicate_line_breaks(code):
             i +=1
     return "\n".join(lines)
 
+# Modules every Move module can use without a `use`, and the items of each the translation may need.
+# `self` stands for the module itself, as in `object::new`. `KeyObject` comes with `implement_key_objects`.
+_IMPLICIT_IMPORTS = {
+    "object": ["self", "KeyObject", "ID", "UID"],
+    "transfer": ["self", "Receiving"],
+    "tx_context": ["self", "TxContext"],
+}
+
 def use_std_libs(code):
     use_lines = []
     indexes_to_delete = set()
@@ -223,21 +361,86 @@ def use_std_libs(code):
     for i in range(len(lines)):
         if "use" not in lines[i]:
             continue
-        breakpoint()
-        if "Balance" in lines[i]:
+        if "sui::sui" in lines[i]:
+            use_lines.append("use crate::sui_std::sui::sui::SUI;")
+            indexes_to_delete.add(i)
+        elif "Balance" in lines[i]:
             use_lines.append("use crate::sui_std::balance::balance;\nuse balance::Balance;")
             indexes_to_delete.add(i)
         elif "Coin" in lines[i]:
             use_lines.append("use crate::sui_std::coin::coin;\nuse coin::Coin;")
             indexes_to_delete.add(i)
+        elif re.search(r'\bdynamic_(object_)?field\b', lines[i]):
+            module = re.search(r'\bdynamic_(object_)?field\b', lines[i]).group(0)
+            alias = re.search(r'\bas\s+(\w+)', lines[i])
+            use_lines.append(f"use crate::sui_std::{module}::{module}" + (f" as {alias.group(1)};" if alias else ";"))
+            indexes_to_delete.add(i)
         elif "transfer" in lines[i]:
-            use_lines.append("use crate::sui_std::transfer::transfer;")
+            indexes_to_delete.add(i) # imported implicitly, see _IMPLICIT_IMPORTS
+        elif re.search(r'\b(object_)?bag\b', lines[i]):
+            module = re.search(r'\b(object_)?bag\b', lines[i]).group(0)
+            kind = "ObjectBag" if module == "object_bag" else "Bag"
+            use_lines.append(f"use crate::sui_std::{module}::{module}::{{self, {kind}}};")
+            indexes_to_delete.add(i)
+        elif "linked_table" in lines[i]:
+            use_lines.append("use crate::sui_std::linked_table::linked_table::{self, LinkedTable};")
+            indexes_to_delete.add(i)
+        elif "object_table" in lines[i]:
+            use_lines.append("use crate::sui_std::object_table::object_table::{self, ObjectTable};")
+            indexes_to_delete.add(i)
+        elif "priority_queue" in lines[i]:
+            use_lines.append("use crate::sui_std::priority_queue::priority_queue::{self, PriorityQueue};")
+            indexes_to_delete.add(i)
+        elif "clock" in lines[i]:
+            use_lines.append("use crate::sui_std::clock::clock::{self, Clock};")
+            indexes_to_delete.add(i)
+        elif re.search(r'\bevent\b', lines[i]):
+            use_lines.append("use crate::sui_std::event::event;")
+            indexes_to_delete.add(i)
+        elif re.search(r'\brandom\b', lines[i]):
+            use_lines.append("use crate::sui_std::random::random::{self, Random, RandomGenerator};")
+            indexes_to_delete.add(i)
+        elif re.search(r'\bpay\b', lines[i]):
+            use_lines.append("use crate::sui_std::pay::pay;")
+            indexes_to_delete.add(i)
+        elif "deny_list" in lines[i]:
+            use_lines.append("use crate::sui_std::deny_list::deny_list::{self, DenyList};")
+            indexes_to_delete.add(i)
+        elif re.search(r'\btoken\b', lines[i]):
+            use_lines.append("use crate::sui_std::token::token::{self, Token, TokenPolicy, TokenPolicyCap, ActionRequest};")
+            indexes_to_delete.add(i)
+        elif "vec_map" in lines[i]:
+            use_lines.append("use crate::sui_std::vec_map::vec_map::{self, VecMap};")
+            indexes_to_delete.add(i)
+        elif "vec_set" in lines[i]:
+            use_lines.append("use crate::sui_std::vec_set::vec_set::{self, VecSet};")
+            indexes_to_delete.add(i)
+        elif "table_vec" in lines[i]:
+            use_lines.append("use crate::sui_std::table_vec::table_vec::{self, TableVec};")
             indexes_to_delete.add(i)
         elif "Table" in lines[i]:
             use_lines.append("use crate::sui_std::table::table::Table;")
             indexes_to_delete.add(i)
+        elif "address" in lines[i]:
+            use_lines.append("use crate::sui_std::address::address::{self, Address};")
+            indexes_to_delete.add(i)
+        elif "tx_context" in lines[i] or "object" in lines[i]:
+            indexes_to_delete.add(i) # imported implicitly, see _IMPLICIT_IMPORTS
 
     lines = [lines[i] for i in range(len(lines)) if i not in indexes_to_delete]
+    body = "\n".join(l for l in lines if not l.startswith("use "))
+    for module, names in _IMPLICIT_IMPORTS.items():
+        used = [n for n in names if re.search(rf'\b{module}::' if n == "self" else rf'\b{n}\b', body)]
+        if used == ["self"]:
+            use_lines.append(f"use crate::sui_std::{module}::{module};")
+        elif len(used) == 1:
+            use_lines.append(f"use crate::sui_std::{module}::{module}::{used[0]};")
+        elif used:
+            use_lines.append(f"use crate::sui_std::{module}::{module}::{{{', '.join(used)}}};")
+    if re.search(r'\bAddress\b', code) and not any("address::address" in l for l in use_lines):
+        use_lines.append("use crate::sui_std::address::address::Address;") # address is a builtin type in Move
+    if re.search(r'\boption::E', code):
+        use_lines.append("use crate::sui_std::option::option;") # std::option is imported by default in Move
 
     return "\n".join(use_lines + lines)
 